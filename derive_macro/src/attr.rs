@@ -0,0 +1,283 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Parsing of `#[if_empty(...)]` attributes
+
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute,
+    Error,
+    Expr,
+    ExprPath,
+    Ident,
+    Lit,
+    LitStr,
+    Path,
+    Result,
+    Token,
+};
+
+/// How the derive generates `is_empty`
+pub enum Predicate {
+    /// `self.len() == 0`
+    Len,
+    /// `is_empty` of the single field
+    Delegate,
+    /// Empty if all fields are empty
+    All,
+    /// Empty if any field is empty
+    Any,
+}
+
+/// Options set on the deriving type with `#[if_empty(...)]`
+#[derive(Default)]
+pub struct ContainerOptions {
+    /// Generate `is_empty` instead of using an existing one
+    pub predicate: Option<Predicate>,
+    /// Generate `Default` from the field defaults
+    pub derive_default: bool,
+    /// Implement `IfEmpty` instead of an inherent `if_empty`
+    pub owned: bool,
+    /// Implement `IfEmptyBorrowed` instead of an inherent `if_empty`
+    pub borrowed: bool,
+    /// Function computing the whole value if it is empty
+    pub with: Option<Expr>,
+    /// Path of the `if_empty` crate if it is re-exported
+    pub krate: Option<Path>,
+    /// Use and implement `IsEmpty` instead of an inherent `is_empty`
+    pub use_trait: bool,
+    /// Mark generated functions `#[inline]`, defaults to `true`
+    pub inline: Option<bool>,
+    /// Mark generated inherent functions `#[must_use]`, defaults to `true`
+    pub must_use: Option<bool>,
+    /// Documentation appended to generated inherent functions
+    pub doc: Option<LitStr>,
+}
+
+impl ContainerOptions {
+    pub fn from_attributes(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+        for entry in parse_options(attrs)? {
+            if entry.key == "len" {
+                entry.expect_flag()?;
+                options.set_predicate(Predicate::Len, &entry.key)?;
+            } else if entry.key == "delegate" {
+                entry.expect_flag()?;
+                options.set_predicate(Predicate::Delegate, &entry.key)?;
+            } else if entry.key == "all" {
+                entry.expect_flag()?;
+                options.set_predicate(Predicate::All, &entry.key)?;
+            } else if entry.key == "any" {
+                entry.expect_flag()?;
+                options.set_predicate(Predicate::Any, &entry.key)?;
+            } else if entry.key == "derive_default" {
+                entry.set_flag(&mut options.derive_default)?;
+            } else if entry.key == "owned" {
+                entry.set_flag(&mut options.owned)?;
+            } else if entry.key == "borrowed" {
+                entry.set_flag(&mut options.borrowed)?;
+            } else if entry.key == "inline" {
+                if options.inline.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.inline = Some(entry.expect_bool()?);
+            } else if entry.key == "must_use" {
+                if options.must_use.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.must_use = Some(entry.expect_bool()?);
+            } else if entry.key == "doc" {
+                if options.doc.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.doc = Some(entry.expect_string()?);
+            } else if entry.key == "trait" {
+                entry.set_flag(&mut options.use_trait)?;
+            } else if entry.key == "with" {
+                if options.with.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.with = Some(entry.expect_function()?);
+            } else if entry.key == "crate" {
+                if options.krate.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.krate = Some(entry.expect_path()?);
+            } else {
+                return Err(entry.unknown());
+            }
+        }
+        Ok(options)
+    }
+
+    /// Returns the path generated code uses for the `if_empty` crate
+    pub fn crate_path(&self) -> Path {
+        self.krate.clone().unwrap_or_else(|| parse_quote!(::if_empty))
+    }
+
+    fn set_predicate(&mut self, predicate: Predicate, key: &Ident) -> Result<()> {
+        if self.predicate.is_some() {
+            return Err(Error::new(key.span(), "only one way of generating `is_empty` can be chosen"));
+        }
+        self.predicate = Some(predicate);
+        Ok(())
+    }
+}
+
+/// Options set on a field with `#[if_empty(...)]`
+#[derive(Default)]
+pub struct FieldOptions {
+    /// Value used for the field if it is empty
+    pub default: Option<Expr>,
+    /// Function computing the field from the whole value if it is empty
+    pub with: Option<Expr>,
+    /// Report the empty fields of the field instead of the field itself
+    pub nested: bool,
+    /// Don't report the field
+    pub skip: bool,
+}
+
+impl FieldOptions {
+    pub fn from_attributes(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+        for entry in parse_options(attrs)? {
+            let key_span = entry.key.span();
+            if entry.key == "default" {
+                if options.default.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.default = Some(entry.expect_value()?);
+            } else if entry.key == "with" {
+                if options.with.is_some() {
+                    return Err(entry.duplicate());
+                }
+                options.with = Some(entry.expect_function()?);
+            } else if entry.key == "nested" {
+                entry.set_flag(&mut options.nested)?;
+            } else if entry.key == "skip" {
+                entry.set_flag(&mut options.skip)?;
+            } else {
+                return Err(entry.unknown());
+            }
+            if options.default.is_some() && options.with.is_some() {
+                return Err(Error::new(key_span, "only one of `default` and `with` can be set on a field"));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// A single `key` or `key = value` entry
+struct Entry {
+    key: Ident,
+    value: Option<Expr>,
+}
+
+impl Entry {
+    fn expect_flag(&self) -> Result<()> {
+        match &self.value {
+            Some(_) => Err(Error::new(
+                self.key.span(),
+                format!("if_empty option `{}` doesn't take a value", self.key),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn set_flag(&self, flag: &mut bool) -> Result<()> {
+        self.expect_flag()?;
+        if *flag {
+            return Err(self.duplicate());
+        }
+        *flag = true;
+        Ok(())
+    }
+
+    fn expect_value(self) -> Result<Expr> {
+        let key = self.key;
+        self.value
+            .ok_or_else(|| Error::new(key.span(), format!("if_empty option `{}` requires a value", key)))
+    }
+
+    /// Returns the value as function, which may also be given as a string like `"path::to::fn"`
+    fn expect_function(self) -> Result<Expr> {
+        match self.expect_value()? {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(path) => Ok(Expr::Path(path.parse::<ExprPath>()?)),
+                _ => Err(Error::new_spanned(lit, "expected a function path")),
+            },
+            function => Ok(function),
+        }
+    }
+
+    /// Returns the value as string literal
+    fn expect_string(self) -> Result<LitStr> {
+        match self.expect_value()? {
+            Expr::Lit(lit) => match lit.lit {
+                Lit::Str(value) => Ok(value),
+                lit => Err(Error::new_spanned(lit, "expected a string")),
+            },
+            value => Err(Error::new_spanned(value, "expected a string")),
+        }
+    }
+
+    /// Returns the value as `bool`, a bare `key` means `true`
+    fn expect_bool(self) -> Result<bool> {
+        match self.value {
+            None => Ok(true),
+            Some(Expr::Lit(lit)) => match &lit.lit {
+                Lit::Bool(value) => Ok(value.value),
+                _ => Err(Error::new_spanned(lit, "expected `true` or `false`")),
+            },
+            Some(value) => Err(Error::new_spanned(value, "expected `true` or `false`")),
+        }
+    }
+
+    /// Returns the value as path, which may also be given as a string like `"path::to::crate"`
+    fn expect_path(self) -> Result<Path> {
+        match self.expect_value()? {
+            Expr::Path(path) if path.qself.is_none() && path.attrs.is_empty() => Ok(path.path),
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(path) => path.parse(),
+                _ => Err(Error::new_spanned(lit, "expected a path")),
+            },
+            value => Err(Error::new_spanned(value, "expected a path")),
+        }
+    }
+
+    fn duplicate(&self) -> Error {
+        Error::new(self.key.span(), format!("duplicate if_empty option `{}`", self.key))
+    }
+
+    fn unknown(&self) -> Error {
+        Error::new(self.key.span(), format!("unknown if_empty option `{}`", self.key))
+    }
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = Ident::parse_any(input)?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            key,
+            value,
+        })
+    }
+}
+
+fn parse_options(attrs: &[Attribute]) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("if_empty")) {
+        entries.extend(attr.parse_args_with(Punctuated::<Entry, Token![,]>::parse_terminated)?);
+    }
+    Ok(entries)
+}
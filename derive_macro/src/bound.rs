@@ -0,0 +1,40 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Inference of `where` bounds for fields using type or const parameters
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{parse_quote, Generics, Ident, Type};
+
+/// Returns `generics` with `ty: bound` added for every type in `types` that uses a type or const parameter
+///
+/// Types not depending on a parameter either implement the trait or fail to compile on their own, so they don't get a bound.
+/// Const parameters need bounds as well, as traits like `Default` are only implemented for some array lengths.
+pub fn with_bounds<'a>(generics: &Generics, types: impl IntoIterator<Item = &'a Type>, bound: &TokenStream) -> Generics {
+    let params: Vec<&Ident> = generics
+        .type_params()
+        .map(|param| &param.ident)
+        .chain(generics.const_params().map(|param| &param.ident))
+        .collect();
+    let mut bounded = generics.clone();
+    let mut seen = Vec::new();
+    for ty in types {
+        let key = ty.to_token_stream().to_string();
+        if seen.contains(&key) || !uses_params(ty.to_token_stream(), &params) {
+            continue;
+        }
+        seen.push(key);
+        bounded.make_where_clause().predicates.push(parse_quote!(#ty: #bound));
+    }
+    bounded
+}
+
+fn uses_params(tokens: TokenStream, params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.iter().any(|param| **param == ident),
+        TokenTree::Group(group) => uses_params(group.stream(), params),
+        _ => false,
+    })
+}
@@ -32,8 +32,17 @@
 //! ```
 
 use proc_macro::{self, TokenStream};
+use proc_macro2::Span;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{ext::IdentExt, parse_macro_input, Data, DeriveInput, Error, Ident, Index, Member, Result};
+
+use crate::{
+    attr::{ContainerOptions, FieldOptions, Predicate},
+    bound::with_bounds,
+};
+
+mod attr;
+mod bound;
 
 /// Implement `if_empty` on types with `is_empty` functions
 ///
@@ -41,16 +50,230 @@ use syn::{parse_macro_input, DeriveInput};
 /// [`if_empty_derive`]: https://docs.rs/if_empty_derive/
 ///
 /// See [`if_empty`] for usage guidelines and [`if_empty_derive`] for implementation constraints.
-#[proc_macro_derive(IfEmpty)]
+///
+/// # Attributes
+///
+/// - `#[if_empty(len)]`: also generate `is_empty` as `self.len() == 0` for types that only have a `len` function
+/// - `#[if_empty(delegate)]`: also generate `is_empty` by delegating to `IsEmpty` of the single field of a newtype
+/// - `#[if_empty(all)]` and `#[if_empty(any)]`: also generate `is_empty` as empty if all or any fields are empty, which requires
+///   all fields to implement `IsEmpty`
+/// - `#[if_empty(trait)]`: call `is_empty` through the `IsEmpty` trait instead of an inherent function, generated `is_empty`
+///   implementations implement the trait
+/// - `#[if_empty(owned)]` and `#[if_empty(borrowed)]`: implement `IfEmpty` and `IfEmptyBorrowed` instead of an inherent `if_empty`,
+///   both can be combined
+/// - `#[if_empty(default = value)]` on fields: generate `fill_empty_defaults` replacing the annotated fields with `value` if they
+///   are empty according to `IsEmpty`
+/// - `#[if_empty(with = path)]` on fields: also replace the field in `fill_empty_defaults` with `path(&self)` if it is empty, so it
+///   can be computed from its siblings
+/// - `#[if_empty(with = path)]`: replace the whole value in `fill_empty_defaults` with `path(&self)` if it is empty, before any
+///   field is filled
+/// - `#[if_empty(crate = "path")]`: use `path` instead of `::if_empty` in generated code if the crate is re-exported
+/// - `#[if_empty(doc = "text")]`: append `text` to the documentation of generated inherent functions
+/// - `#[if_empty(inline = false)]` and `#[if_empty(must_use = false)]`: don't mark generated functions `#[inline]` and generated
+///   inherent functions `#[must_use]`, both are by default
+/// - `#[if_empty(derive_default)]`: also implement `Default` using the field defaults and `Default::default()` for fields without
+///   one
+///
+/// # Generics
+///
+/// Generic types, including const generics, are supported. For fields whose type uses a type or const parameter the generated impls
+/// are bound on the field type implementing `IsEmpty` if its `is_empty` is called, and `Default` for `derive_default` fields
+/// without a default.
+///
+/// # Unsupported types
+///
+/// Unions and enums without variants are rejected with a compile error.
+///
+/// ```compile_fail
+/// # use if_empty_derive::IfEmpty;
+/// #[derive(IfEmpty)]
+/// union Bits {
+///     int: u32,
+///     float: f32,
+/// }
+/// # impl Bits {
+/// #     fn is_empty(&self) -> bool {
+/// #         true
+/// #     }
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use if_empty_derive::IfEmpty;
+/// #[derive(IfEmpty)]
+/// enum Never {}
+/// # impl Never {
+/// #     fn is_empty(&self) -> bool {
+/// #         match *self {}
+/// #     }
+/// # }
+/// ```
+#[proc_macro_derive(IfEmpty, attributes(if_empty))]
 pub fn if_empty(input: TokenStream) -> TokenStream {
-    let DeriveInput {
-        ident, ..
-    } = parse_macro_input!(input);
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_if_empty(&input).unwrap_or_else(Error::into_compile_error).into()
+}
 
-    let output = quote! {
-        impl #ident {
+fn expand_if_empty(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    match &input.data {
+        Data::Union(_) => return Err(Error::new(ident.span(), "IfEmpty can't be derived for unions")),
+        Data::Enum(data) if data.variants.is_empty() => {
+            return Err(Error::new(
+                ident.span(),
+                "IfEmpty can't be derived for enums without variants",
+            ))
+        }
+        _ => {}
+    }
+    let options = ContainerOptions::from_attributes(&input.attrs)?;
+    let krate = options.crate_path();
+
+    let inline = if options.inline.unwrap_or(true) {
+        quote! { #[inline] }
+    } else {
+        quote! {}
+    };
+    let must_use = if options.must_use.unwrap_or(true) {
+        quote! { #[must_use] }
+    } else {
+        quote! {}
+    };
+
+    let doc = options.doc.as_ref().map(|doc| quote! { #[doc = ""] #[doc = #doc] });
+
+    let fields = field_options(&input.data)?;
+    let field_types: Vec<_> = match &input.data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        _ => Vec::new(),
+    };
+    // Fields whose `is_empty` is called by the generated code
+    let checked = field_types.iter().zip(&fields).filter_map(|(ty, (_, field))| {
+        let predicate = matches!(
+            options.predicate,
+            Some(Predicate::Delegate) | Some(Predicate::All) | Some(Predicate::Any)
+        );
+        if predicate || field.default.is_some() || field.with.is_some() {
+            Some(*ty)
+        } else {
+            None
+        }
+    });
+    let generics = with_bounds(&input.generics, checked, &quote! { #krate::IsEmpty });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (is_empty, trait_is_empty) = match predicate(ident, &options, &input.data)? {
+        Some(predicate) => {
+            let is_empty = quote! {
+                #inline
+                fn is_empty(&self) -> bool {
+                    #predicate
+                }
+            };
+            if options.use_trait {
+                (
+                    quote! {},
+                    quote! { impl #impl_generics #krate::IsEmpty for #ident #ty_generics #where_clause { #is_empty } },
+                )
+            } else {
+                (
+                    quote! {
+                        /// Returns `true` if `self` is empty
+                        #doc
+                        #must_use
+                        #is_empty
+                    },
+                    quote! {},
+                )
+            }
+        }
+        None => (quote! {}, quote! {}),
+    };
+    let is_empty_call = if options.use_trait {
+        quote! { <Self as #krate::IsEmpty>::is_empty(&self) }
+    } else {
+        quote! { self.is_empty() }
+    };
+
+    let filled = fields
+        .iter()
+        .filter_map(|(member, field)| match (&field.default, &field.with) {
+            (Some(default), _) => Some(quote! {
+                if #krate::IsEmpty::is_empty(&self.#member) {
+                    self.#member = ::std::convert::Into::into(#default);
+                }
+            }),
+            (None, Some(with)) => Some(quote! {
+                if #krate::IsEmpty::is_empty(&self.#member) {
+                    let value = (#with)(&self);
+                    self.#member = value;
+                }
+            }),
+            (None, None) => None,
+        });
+    let filled_container = options.with.as_ref().map(|with| {
+        quote! {
+            if #is_empty_call {
+                self = (#with)(&self);
+            }
+        }
+    });
+    let fill_empty_defaults = if options.with.is_some()
+        || fields
+            .iter()
+            .any(|(_, field)| field.default.is_some() || field.with.is_some())
+    {
+        quote! {
+            /// Replaces empty fields with their `#[if_empty(...)]` defaults
+            #doc
+            #inline
+            #must_use
+            fn fill_empty_defaults(mut self) -> Self {
+                #filled_container
+                #(#filled)*
+                self
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let default_impl = if options.derive_default {
+        if !matches!(input.data, Data::Struct(_)) {
+            return Err(Error::new(ident.span(), "`derive_default` requires a struct"));
+        }
+        let defaulted = field_types
+            .iter()
+            .zip(&fields)
+            .filter(|(_, (_, field))| field.default.is_none())
+            .map(|(ty, _)| *ty);
+        let generics = with_bounds(&input.generics, defaulted, &quote! { ::std::default::Default });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let fields = fields.iter().map(|(member, field)| match &field.default {
+            Some(default) => quote! { #member: ::std::convert::Into::into(#default) },
+            None => quote! { #member: ::std::default::Default::default() },
+        });
+        quote! {
+            impl #impl_generics ::std::default::Default for #ident #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self { #(#fields),* }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let if_empty = if options.owned || options.borrowed {
+        quote! {}
+    } else {
+        quote! {
+            /// Returns `input` if `self` is empty, otherwise `self`
+            #doc
+            #inline
+            #must_use
             fn if_empty(self, input: Self) -> Self {
-                if self.is_empty() {
+                if #is_empty_call {
                     input
                 } else {
                     self
@@ -59,5 +282,320 @@ pub fn if_empty(input: TokenStream) -> TokenStream {
         }
     };
 
-    output.into()
+    let owned_impl = if options.owned {
+        quote! {
+            impl #impl_generics #krate::IfEmpty for #ident #ty_generics #where_clause {
+                #inline
+                fn if_empty(self, input: Self) -> Self {
+                    if #is_empty_call {
+                        input
+                    } else {
+                        self
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let borrowed_impl = if options.borrowed {
+        quote! {
+            impl #impl_generics #krate::IfEmptyBorrowed for #ident #ty_generics #where_clause {
+                #inline
+                fn if_empty<'a>(&'a self, input: &'a Self) -> &'a Self {
+                    if #is_empty_call {
+                        input
+                    } else {
+                        self
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #is_empty
+
+            #fill_empty_defaults
+
+            #if_empty
+        }
+
+        #trait_is_empty
+
+        #owned_impl
+
+        #borrowed_impl
+
+        #default_impl
+    })
+}
+
+/// Returns the fields of a struct with their `#[if_empty(...)]` options
+fn field_options(data: &Data) -> Result<Vec<(Member, FieldOptions)>> {
+    let fields = match data {
+        Data::Struct(data) => &data.fields,
+        _ => return Ok(Vec::new()),
+    };
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = match &field.ident {
+                Some(name) => Member::Named(name.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            };
+            Ok((member, FieldOptions::from_attributes(&field.attrs)?))
+        })
+        .collect()
+}
+
+/// Returns the body of the generated `is_empty` if one is requested
+fn predicate(ident: &Ident, options: &ContainerOptions, data: &Data) -> Result<Option<proc_macro2::TokenStream>> {
+    let krate = options.crate_path();
+    let predicate = match options.predicate {
+        Some(Predicate::Len) => quote! { self.len() == 0 },
+        Some(Predicate::Delegate) => {
+            let field = single_field(ident, data)?;
+            quote! { #krate::IsEmpty::is_empty(&self.#field) }
+        }
+        Some(Predicate::All) => {
+            let fields = struct_fields(ident, data, "all")?;
+            quote! { true #(&& #krate::IsEmpty::is_empty(&self.#fields))* }
+        }
+        Some(Predicate::Any) => {
+            let fields = struct_fields(ident, data, "any")?;
+            quote! { false #(|| #krate::IsEmpty::is_empty(&self.#fields))* }
+        }
+        None => return Ok(None),
+    };
+    Ok(Some(predicate))
+}
+
+/// Returns the only field of a newtype struct
+fn single_field(ident: &Ident, data: &Data) -> Result<Member> {
+    let fields = match data {
+        Data::Struct(data) if data.fields.len() == 1 => &data.fields,
+        _ => {
+            return Err(Error::new(
+                ident.span(),
+                "`delegate` requires a struct with exactly one field",
+            ))
+        }
+    };
+    let field = fields.iter().next().expect("one field");
+    Ok(match &field.ident {
+        Some(name) => Member::Named(name.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    })
+}
+
+/// Returns all fields of a struct
+fn struct_fields(ident: &Ident, data: &Data, option: &str) -> Result<Vec<Member>> {
+    let fields = match data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new(ident.span(), format!("`{}` requires a struct", option))),
+    };
+    Ok(fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        })
+        .collect())
+}
+
+/// Implement `MergeEmpty` on structs by merging them field by field
+///
+/// [`MergeEmpty`]: https://docs.rs/if_empty/latest/if_empty/trait.MergeEmpty.html
+///
+/// Every field has to implement [`MergeEmpty`] itself. `#[if_empty(crate = "path")]` is supported as with `IfEmpty`.
+///
+/// ```compile_fail
+/// # use if_empty_derive::MergeEmpty;
+/// #[derive(MergeEmpty)]
+/// enum Source {
+///     Cli,
+///     Env,
+/// }
+/// ```
+#[proc_macro_derive(MergeEmpty, attributes(if_empty))]
+pub fn merge_empty(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_field_wise(&input, "MergeEmpty", "merge_from", FieldWiseArgument::Owned)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Implement `FillDefaults` on structs by filling them field by field
+///
+/// [`FillDefaults`]: https://docs.rs/if_empty/latest/if_empty/trait.FillDefaults.html
+///
+/// Every field has to implement [`FillDefaults`] itself. `#[if_empty(crate = "path")]` is supported as with `IfEmpty`.
+#[proc_macro_derive(FillDefaults, attributes(if_empty))]
+pub fn fill_defaults(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_field_wise(&input, "FillDefaults", "fill_defaults", FieldWiseArgument::Borrowed)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// How the other value is passed to the method of a field-wise derive
+enum FieldWiseArgument {
+    /// `other: Self`
+    Owned,
+    /// `defaults: &Self`
+    Borrowed,
+}
+
+/// Implements `trait_name` on structs by calling `method` with the matching fields of `self` and the other value
+///
+/// Fields whose type uses a type or const parameter get a bound on `trait_name`.
+fn expand_field_wise(
+    input: &DeriveInput,
+    trait_name: &str,
+    method: &str,
+    argument: FieldWiseArgument,
+) -> Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new(
+                ident.span(),
+                format!("{} can only be derived for structs", trait_name),
+            ))
+        }
+    };
+    let krate = ContainerOptions::from_attributes(&input.attrs)?.crate_path();
+    let trait_name = Ident::new(trait_name, Span::call_site());
+    let method = Ident::new(method, Span::call_site());
+    let (parameter, other, other_type) = match argument {
+        FieldWiseArgument::Owned => (quote! { other }, quote! { other }, quote! { Self }),
+        FieldWiseArgument::Borrowed => (quote! { defaults }, quote! { &defaults }, quote! { &Self }),
+    };
+
+    let fields = data.fields.iter().enumerate().map(|(index, field)| {
+        let member = match &field.ident {
+            Some(name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        quote! { #member: #krate::#trait_name::#method(self.#member, #other.#member) }
+    });
+
+    let generics = with_bounds(
+        &input.generics,
+        data.fields.iter().map(|field| &field.ty),
+        &quote! { #krate::#trait_name },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::#trait_name for #ident #ty_generics #where_clause {
+            fn #method(self, #parameter: #other_type) -> Self {
+                Self { #(#fields),* }
+            }
+        }
+    })
+}
+
+/// Implement `EmptyReport` on structs by checking them field by field
+///
+/// [`EmptyReport`]: https://docs.rs/if_empty/latest/if_empty/trait.EmptyReport.html
+///
+/// Every field has to implement `IsEmpty`, fields annotated with `#[if_empty(nested)]` have to implement [`EmptyReport`]
+/// themselves and fields annotated with `#[if_empty(skip)]` are ignored. `#[if_empty(crate = "path")]` is supported as with
+/// `IfEmpty`.
+#[proc_macro_derive(EmptyReport, attributes(if_empty))]
+pub fn empty_report(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_empty_report(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand_empty_report(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let ident = &input.ident;
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => return Err(Error::new(ident.span(), "EmptyReport can only be derived for structs")),
+    };
+    let krate = ContainerOptions::from_attributes(&input.attrs)?.crate_path();
+    let fields = field_options(&input.data)?;
+
+    let mut checked = Vec::new();
+    let mut nested = Vec::new();
+    let mut collected = Vec::new();
+    for (field, (member, options)) in data.fields.iter().zip(&fields) {
+        if options.nested && options.skip {
+            return Err(Error::new_spanned(
+                field,
+                "only one of `nested` and `skip` can be set on a field",
+            ));
+        }
+        let name = match member {
+            Member::Named(name) => name.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        if options.skip {
+            continue;
+        } else if options.nested {
+            nested.push(&field.ty);
+            let prefix = format!("{}.", name);
+            collected.push(quote! {
+                #krate::EmptyReport::collect_empty_fields(&self.#member, &::std::format!("{}{}", prefix, #prefix), fields);
+            });
+        } else {
+            checked.push(&field.ty);
+            collected.push(quote! {
+                if #krate::IsEmpty::is_empty(&self.#member) {
+                    fields.push(::std::format!("{}{}", prefix, #name));
+                }
+            });
+        }
+    }
+
+    let generics = with_bounds(&input.generics, checked, &quote! { #krate::IsEmpty });
+    let generics = with_bounds(&generics, nested, &quote! { #krate::EmptyReport });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #krate::EmptyReport for #ident #ty_generics #where_clause {
+            fn collect_empty_fields(&self, prefix: &str, fields: &mut ::std::vec::Vec<::std::string::String>) {
+                #(#collected)*
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::DeriveInput;
+
+    use super::{expand_field_wise, expand_if_empty, FieldWiseArgument};
+
+    fn if_empty_error(input: &str) -> String {
+        let input: DeriveInput = syn::parse_str(input).unwrap();
+        expand_if_empty(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn unsupported_types() {
+        assert_eq!(
+            if_empty_error("union Bits { int: u32, float: f32 }"),
+            "IfEmpty can't be derived for unions"
+        );
+        assert_eq!(
+            if_empty_error("enum Never {}"),
+            "IfEmpty can't be derived for enums without variants"
+        );
+    }
+
+    #[test]
+    fn field_wise_requires_struct() {
+        let input: DeriveInput = syn::parse_str("enum Mode { Fast, Slow }").unwrap();
+        let error = expand_field_wise(&input, "MergeEmpty", "merge_from", FieldWiseArgument::Owned).unwrap_err();
+        assert_eq!(error.to_string(), "MergeEmpty can only be derived for structs");
+    }
 }
@@ -0,0 +1,284 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+};
+
+/// Returns `true` if `c` counts as whitespace for blank checks
+#[cfg(not(feature = "unicode"))]
+pub(crate) fn is_blank_char(c: char) -> bool {
+    c.is_ascii_whitespace()
+}
+
+/// Returns `true` if `c` counts as whitespace for blank checks
+#[cfg(feature = "unicode")]
+pub(crate) fn is_blank_char(c: char) -> bool {
+    c.is_whitespace()
+}
+
+/// Returns `true` if `s` is empty or only consists of whitespace
+pub(crate) fn is_blank_str(s: &str) -> bool {
+    s.chars().all(is_blank_char)
+}
+
+/// Returns `true` if `s` is empty or only consists of whitespace
+///
+/// Whitespace is always valid unicode, so values that aren't are never blank.
+fn is_blank_os_str(s: &OsStr) -> bool {
+    matches!(s.to_str(), Some(s) if is_blank_str(s))
+}
+
+/// For checking IfBlank on strings and OS strings
+///
+/// A value is blank if it is empty or only consists of whitespace. By default only ASCII whitespace
+/// is considered, with the `unicode` feature enabled all characters with the unicode `White_Space`
+/// property count, e.g. non-breaking and ideographic spaces.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfBlank;
+///
+/// assert_eq!("   ".if_blank("anonymous"), "anonymous");
+/// assert_eq!(" ferris ".to_string().if_blank("anonymous".to_string()), " ferris ");
+/// ```
+pub trait IfBlank: Sized {
+    /// Returns `true` if `self` is empty or only consists of whitespace
+    fn is_blank(&self) -> bool;
+
+    /// Returns `val` if `self` is blank
+    fn if_blank(self, val: Self) -> Self {
+        if self.is_blank() {
+            val
+        } else {
+            self
+        }
+    }
+
+    /// Returns `self` without leading and trailing whitespace
+    ///
+    /// Returns `self` unchanged by default, types that can contain whitespace should override it.
+    fn trimmed(self) -> Self {
+        self
+    }
+
+    /// Returns `val` if `self` is blank.
+    /// Otherwise `self` is returned without leading and trailing whitespace.
+    fn if_empty_trimmed(self, val: Self) -> Self {
+        if self.is_blank() {
+            val
+        } else {
+            self.trimmed()
+        }
+    }
+}
+
+/// Returns `s` without leading and trailing whitespace
+fn trim_blank(s: &str) -> &str {
+    s.trim_matches(is_blank_char)
+}
+
+/// Trims `s` only reallocating if there is whitespace to remove
+fn trim_blank_string(s: String) -> String {
+    let trimmed = trim_blank(&s);
+    if trimmed.len() == s.len() {
+        s
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Returns `s` without leading and trailing whitespace
+///
+/// On unix values that aren't valid unicode are trimmed of ASCII whitespace, on other platforms they are returned
+/// unchanged.
+fn trim_blank_os_str(s: &OsStr) -> &OsStr {
+    if let Some(s) = s.to_str() {
+        return OsStr::new(trim_blank(s));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let bytes = s.as_bytes();
+        let start = bytes.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(bytes.len());
+        let end = bytes
+            .iter()
+            .rposition(|b| !b.is_ascii_whitespace())
+            .map_or(start, |end| end + 1);
+        OsStr::from_bytes(&bytes[start..end])
+    }
+    #[cfg(not(unix))]
+    s
+}
+
+/// Implementation of `IfBlank` for [`str`]
+///
+/// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
+impl IfBlank for &str {
+    fn is_blank(&self) -> bool {
+        is_blank_str(self)
+    }
+
+    fn trimmed(self) -> Self {
+        trim_blank(self)
+    }
+}
+
+/// Implementation of `IfBlank` for [`String`]
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+impl IfBlank for String {
+    fn is_blank(&self) -> bool {
+        is_blank_str(self)
+    }
+
+    fn trimmed(self) -> Self {
+        trim_blank_string(self)
+    }
+}
+
+/// Implementation of `IfBlank` for [`Cow<str>`]
+///
+/// [`Cow<str>`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+impl IfBlank for Cow<'_, str> {
+    fn is_blank(&self) -> bool {
+        is_blank_str(self)
+    }
+
+    fn trimmed(self) -> Self {
+        match self {
+            Cow::Borrowed(value) => Cow::Borrowed(trim_blank(value)),
+            Cow::Owned(value) => Cow::Owned(trim_blank_string(value)),
+        }
+    }
+}
+
+/// Implementation of `IfBlank` for [`OsStr`]
+///
+/// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+impl IfBlank for &OsStr {
+    fn is_blank(&self) -> bool {
+        is_blank_os_str(self)
+    }
+
+    fn trimmed(self) -> Self {
+        trim_blank_os_str(self)
+    }
+}
+
+/// Implementation of `IfBlank` for [`OsString`]
+///
+/// [`OsString`]: https://doc.rust-lang.org/std/ffi/struct.OsString.html
+impl IfBlank for OsString {
+    fn is_blank(&self) -> bool {
+        is_blank_os_str(self)
+    }
+
+    fn trimmed(self) -> Self {
+        let trimmed = trim_blank_os_str(&self);
+        if trimmed.len() == self.len() {
+            self
+        } else {
+            trimmed.to_os_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        ffi::{OsStr, OsString},
+    };
+
+    use crate::IfBlank;
+
+    #[test]
+    fn str() {
+        assert_eq!("".if_blank("fallback"), "fallback");
+        assert_eq!(" \t\r\n".if_blank("fallback"), "fallback");
+        assert_eq!(" text ".if_blank("fallback"), " text ");
+    }
+
+    #[test]
+    fn string() {
+        assert_eq!(String::new().if_blank("fallback".to_string()), "fallback");
+        assert_eq!("  ".to_string().if_blank("fallback".to_string()), "fallback");
+        assert_eq!("text".to_string().if_blank("fallback".to_string()), "text");
+    }
+
+    #[test]
+    fn if_empty_trimmed() {
+        assert_eq!("  ".if_empty_trimmed("fallback"), "fallback");
+        assert_eq!(" text\n".if_empty_trimmed("fallback"), "text");
+        assert_eq!(" ".to_string().if_empty_trimmed(" fallback ".to_string()), " fallback ");
+        assert_eq!("\ttext ".to_string().if_empty_trimmed("fallback".to_string()), "text");
+        assert_eq!("text".to_string().if_empty_trimmed("fallback".to_string()), "text");
+        assert_eq!(Cow::Borrowed(" text ").if_empty_trimmed(Cow::Borrowed("fallback")), "text");
+        assert_eq!(
+            Cow::<str>::Owned(" ".to_string()).if_empty_trimmed(Cow::Borrowed("fallback")),
+            "fallback"
+        );
+    }
+
+    #[test]
+    fn unicode() {
+        let blank = "\u{a0}\u{3000}";
+        assert_eq!(blank.is_blank(), cfg!(feature = "unicode"));
+    }
+
+    #[test]
+    fn os_str() {
+        assert_eq!(OsStr::new(" \t").if_blank(OsStr::new("fallback")), "fallback");
+        assert_eq!(OsStr::new(" text ").if_blank(OsStr::new("fallback")), " text ");
+        assert_eq!(OsStr::new(" text ").if_empty_trimmed(OsStr::new("fallback")), "text");
+        assert_eq!(OsString::from("\n").if_blank(OsString::from("fallback")), "fallback");
+        assert_eq!(OsString::from(" text").if_empty_trimmed(OsString::from("fallback")), "text");
+        assert_eq!(
+            OsString::from("  ").if_empty_trimmed(OsString::from(" fallback ")),
+            " fallback "
+        );
+    }
+
+    #[test]
+    fn provided() {
+        #[derive(Debug, PartialEq)]
+        struct Answer(Option<char>);
+
+        impl IfBlank for Answer {
+            fn is_blank(&self) -> bool {
+                match self.0 {
+                    Some(answer) => answer.is_whitespace(),
+                    None => true,
+                }
+            }
+        }
+
+        assert_eq!(Answer(Some(' ')).if_empty_trimmed(Answer(Some('n'))), Answer(Some('n')));
+        assert_eq!(Answer(Some('y')).if_empty_trimmed(Answer(Some('n'))), Answer(Some('y')));
+        assert_eq!(Answer(None).if_blank(Answer(Some('n'))), Answer(Some('n')));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_not_unicode() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let value = OsStr::from_bytes(b" \xff ");
+        assert!(!value.is_blank());
+        assert_eq!(value.if_empty_trimmed(OsStr::new("fallback")).as_bytes(), b"\xff");
+    }
+
+    #[test]
+    fn cow() {
+        assert_eq!(Cow::Borrowed(" ").if_blank(Cow::Borrowed("fallback")), "fallback");
+        assert_eq!(
+            Cow::<str>::Owned("text".to_string()).if_blank(Cow::Borrowed("fallback")),
+            "text"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::IsEmpty;
+
+/// Resolves a value from multiple sources, using the first non-empty one
+///
+/// Sources added with [`or_with`] are only evaluated if all prior ones were empty.
+///
+/// [`or_with`]: FallbackChain::or_with
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::FallbackChain;
+///
+/// let cli = String::new();
+/// let env = String::new();
+/// let host = FallbackChain::new(cli).or(env).or_with(|| "from file".to_string()).resolve("localhost".to_string());
+/// assert_eq!(host, "from file");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallbackChain<T> {
+    value: Option<T>,
+}
+
+impl<T: IsEmpty> FallbackChain<T> {
+    /// Starts a chain with `primary` as the most specific source
+    pub fn new(primary: T) -> Self {
+        Self {
+            value: primary.empty_to_none(),
+        }
+    }
+
+    /// Adds `value` as the next source
+    pub fn or(self, value: T) -> Self {
+        match self.value {
+            Some(_) => self,
+            None => Self::new(value),
+        }
+    }
+
+    /// Adds the result of `f` as the next source
+    ///
+    /// `f` is only called if all prior sources were empty.
+    pub fn or_with<F: FnOnce() -> T>(self, f: F) -> Self {
+        match self.value {
+            Some(_) => self,
+            None => Self::new(f()),
+        }
+    }
+
+    /// Returns the first non-empty value or `default` if all sources were empty
+    pub fn resolve(self, default: T) -> T {
+        self.value.unwrap_or(default)
+    }
+
+    /// Returns the first non-empty value or the result of `f` if all sources were empty
+    pub fn resolve_with<F: FnOnce() -> T>(self, f: F) -> T {
+        self.value.unwrap_or_else(f)
+    }
+
+    /// Returns the first non-empty value or `None` if all sources were empty
+    pub fn into_option(self) -> Option<T> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FallbackChain;
+
+    #[test]
+    fn resolve() {
+        assert_eq!(FallbackChain::new("primary").or("second").resolve("default"), "primary");
+        assert_eq!(FallbackChain::new("").or("second").resolve("default"), "second");
+        assert_eq!(FallbackChain::new("").or("").resolve("default"), "default");
+        assert_eq!(FallbackChain::new(Vec::<u8>::new()).resolve_with(|| vec![1]), vec![1]);
+    }
+
+    #[test]
+    fn lazy() {
+        let value = FallbackChain::new("primary")
+            .or_with(|| unreachable!())
+            .resolve_with(|| unreachable!());
+        assert_eq!(value, "primary");
+
+        let mut evaluated = Vec::new();
+        let value = FallbackChain::new(String::new())
+            .or_with(|| {
+                evaluated.push(1);
+                String::new()
+            })
+            .or_with(|| {
+                evaluated.push(2);
+                "third".to_string()
+            })
+            .into_option();
+        assert_eq!(value, Some("third".to_string()));
+        assert_eq!(evaluated, [1, 2]);
+    }
+}
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::IsEmpty;
+
+/// For checking IfAllEmpty on collections whose elements can be empty
+///
+/// A collection is all empty if it has no elements or all of its elements or values are empty, which
+/// [`IsEmpty::is_empty`] misses for e.g. `vec!["", ""]`.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfAllEmpty;
+///
+/// let tags = vec![String::new(), String::new()];
+/// assert_eq!(tags.if_all_empty(vec!["untagged".to_string()]), ["untagged"]);
+/// ```
+pub trait IfAllEmpty: Sized {
+    /// Returns `true` if all elements of `self` are empty
+    fn is_all_empty(&self) -> bool;
+
+    /// Returns `val` if all elements of `self` are empty
+    fn if_all_empty(self, val: Self) -> Self {
+        if self.is_all_empty() {
+            val
+        } else {
+            self
+        }
+    }
+}
+
+/// Implementation of `IfAllEmpty` for [`Vec`]
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+impl<T: IsEmpty> IfAllEmpty for Vec<T> {
+    fn is_all_empty(&self) -> bool {
+        self.iter().all(IsEmpty::is_empty)
+    }
+}
+
+/// Implementation of `IfAllEmpty` for [`VecDeque`]
+///
+/// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+impl<T: IsEmpty> IfAllEmpty for VecDeque<T> {
+    fn is_all_empty(&self) -> bool {
+        self.iter().all(IsEmpty::is_empty)
+    }
+}
+
+/// Implementation of `IfAllEmpty` for [`HashMap`], checking its values
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+impl<K, V: IsEmpty, S> IfAllEmpty for HashMap<K, V, S> {
+    fn is_all_empty(&self) -> bool {
+        self.values().all(IsEmpty::is_empty)
+    }
+}
+
+/// Implementation of `IfAllEmpty` for [`BTreeMap`], checking its values
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+impl<K, V: IsEmpty> IfAllEmpty for BTreeMap<K, V> {
+    fn is_all_empty(&self) -> bool {
+        self.values().all(IsEmpty::is_empty)
+    }
+}
+
+/// For removing empty elements from collections
+///
+/// Maps remove the entries with empty values.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::RetainNonEmpty;
+///
+/// let mut args = vec!["--verbose".to_string(), String::new(), "--color".to_string()];
+/// args.retain_non_empty();
+/// assert_eq!(args, ["--verbose", "--color"]);
+/// ```
+pub trait RetainNonEmpty {
+    /// Removes all empty elements or entries with empty values
+    fn retain_non_empty(&mut self);
+}
+
+/// Implementation of `RetainNonEmpty` for [`Vec`]
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+impl<T: IsEmpty> RetainNonEmpty for Vec<T> {
+    fn retain_non_empty(&mut self) {
+        self.retain(|element| !element.is_empty())
+    }
+}
+
+/// Implementation of `RetainNonEmpty` for [`VecDeque`]
+///
+/// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+impl<T: IsEmpty> RetainNonEmpty for VecDeque<T> {
+    fn retain_non_empty(&mut self) {
+        self.retain(|element| !element.is_empty())
+    }
+}
+
+/// Implementation of `RetainNonEmpty` for [`HashMap`]
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+impl<K, V: IsEmpty, S> RetainNonEmpty for HashMap<K, V, S> {
+    fn retain_non_empty(&mut self) {
+        self.retain(|_, value| !value.is_empty())
+    }
+}
+
+/// Implementation of `RetainNonEmpty` for [`BTreeMap`]
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+impl<K: Ord, V: IsEmpty> RetainNonEmpty for BTreeMap<K, V> {
+    fn retain_non_empty(&mut self) {
+        self.retain(|_, value| !value.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+
+    use crate::{IfAllEmpty, RetainNonEmpty};
+
+    #[test]
+    fn vec() {
+        assert_eq!(vec!["", "", ""].if_all_empty(vec!["fallback"]), ["fallback"]);
+        assert_eq!(Vec::<&str>::new().if_all_empty(vec!["fallback"]), ["fallback"]);
+        assert_eq!(vec!["", "value"].if_all_empty(vec!["fallback"]), ["", "value"]);
+        assert!(VecDeque::from(vec![Vec::<u8>::new()]).is_all_empty());
+    }
+
+    #[test]
+    fn map() {
+        let map: HashMap<_, _> = vec![("a", ""), ("b", "")].into_iter().collect();
+        assert!(map.is_all_empty());
+
+        let map: BTreeMap<_, _> = vec![(1, String::new()), (2, "value".to_string())].into_iter().collect();
+        assert_eq!(map.clone().if_all_empty(BTreeMap::new()), map);
+    }
+
+    #[test]
+    fn retain_non_empty() {
+        let mut vec = vec!["", "a", "", "b"];
+        vec.retain_non_empty();
+        assert_eq!(vec, ["a", "b"]);
+
+        let mut deque = VecDeque::from(vec![vec![1], vec![]]);
+        deque.retain_non_empty();
+        assert_eq!(deque, [vec![1]]);
+
+        let mut map: HashMap<_, _> = vec![("a", ""), ("b", "value")].into_iter().collect();
+        map.retain_non_empty();
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), [("b", "value")]);
+
+        let mut map: BTreeMap<_, _> = vec![(1, String::new()), (2, String::new())].into_iter().collect();
+        map.retain_non_empty();
+        assert!(map.is_empty());
+    }
+}
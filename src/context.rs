@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::IsEmpty;
+
+/// For defaults computed from a context value
+///
+/// Allows the context specific defaults to be derived from e.g. the current request, tenant or locale
+/// instead of being hard-coded at each call site.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::ContextDefault;
+///
+/// struct Tenant {
+///     domain: &'static str,
+/// }
+///
+/// struct Email(String);
+///
+/// impl ContextDefault<Tenant> for Email {
+///     fn default_for(tenant: &Tenant) -> Self {
+///         Email(format!("support@{}", tenant.domain))
+///     }
+/// }
+///
+/// # impl if_empty::IsEmpty for Email {
+/// #     fn is_empty(&self) -> bool {
+/// #         self.0.is_empty()
+/// #     }
+/// # }
+/// let tenant = Tenant {
+///     domain: "example.com",
+/// };
+/// assert_eq!(Email(String::new()).if_empty_for(&tenant).0, "support@example.com");
+/// ```
+pub trait ContextDefault<C: ?Sized> {
+    /// Returns the default value for `ctx`
+    fn default_for(ctx: &C) -> Self;
+
+    /// Returns the default value for `ctx` if `self` is empty
+    ///
+    /// The default is only computed if `self` is empty.
+    fn if_empty_for(self, ctx: &C) -> Self
+    where
+        Self: IsEmpty + Sized,
+    {
+        if self.is_empty() {
+            Self::default_for(ctx)
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ContextDefault;
+
+    struct Locale(&'static str);
+
+    #[derive(Debug, PartialEq)]
+    struct Greeting(String);
+
+    impl ContextDefault<Locale> for Greeting {
+        fn default_for(locale: &Locale) -> Self {
+            match locale.0 {
+                "de" => Greeting("Hallo".to_string()),
+                _ => Greeting("Hello".to_string()),
+            }
+        }
+    }
+
+    impl ContextDefault<str> for String {
+        fn default_for(ctx: &str) -> Self {
+            format!("{}-default", ctx)
+        }
+    }
+
+    impl crate::IsEmpty for Greeting {
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    #[test]
+    fn if_empty_for() {
+        assert_eq!(Greeting(String::new()).if_empty_for(&Locale("de")).0, "Hallo");
+        assert_eq!(Greeting(String::new()).if_empty_for(&Locale("en")).0, "Hello");
+        assert_eq!(Greeting("Servus".to_string()).if_empty_for(&Locale("de")).0, "Servus");
+        assert_eq!(String::new().if_empty_for("tenant"), "tenant-default");
+    }
+}
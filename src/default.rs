@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// For checking IfDefault on values equal to their [`Default`]
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+///
+/// This generalizes emptiness to every comparable type, e.g. structs deserialized with
+/// `#[serde(default)]`. It is implemented for all types implementing [`PartialEq`] and [`Default`].
+///
+/// [`PartialEq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfDefault;
+///
+/// assert_eq!(0.if_default(8080), 8080);
+/// assert_eq!(Some(1).if_default(Some(2)), Some(1));
+/// ```
+pub trait IfDefault: PartialEq + Default {
+    /// Returns `val` if `self` equals `Self::default()`
+    fn if_default(self, val: Self) -> Self;
+}
+
+impl<T: PartialEq + Default> IfDefault for T {
+    fn if_default(self, val: Self) -> Self {
+        if self == Self::default() {
+            val
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IfDefault;
+
+    #[test]
+    fn if_default() {
+        assert_eq!(0u16.if_default(8080), 8080);
+        assert_eq!(443u16.if_default(8080), 443);
+        assert!(false.if_default(true));
+        assert_eq!(None.if_default(Some("fallback")), Some("fallback"));
+        assert_eq!(String::new().if_default("fallback".to_string()), "fallback");
+    }
+
+    #[test]
+    fn custom() {
+        #[derive(Debug, Default, PartialEq)]
+        struct Limits {
+            connections: u32,
+            timeout: u32,
+        }
+
+        let fallback = || Limits {
+            connections: 16,
+            timeout: 30,
+        };
+        assert_eq!(Limits::default().if_default(fallback()), fallback());
+
+        let limits = Limits {
+            connections: 0,
+            timeout: 10,
+        };
+        assert_eq!(limits.if_default(fallback()).timeout, 10);
+    }
+}
@@ -0,0 +1,66 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt;
+
+use crate::IsEmpty;
+
+/// Displays `placeholder` instead of `value` if `value` is empty
+///
+/// Avoids substituting a temporary [`String`] just for output.
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::display_or;
+///
+/// let email = "";
+/// assert_eq!(format!("email: {}", display_or(email, "—")), "email: —");
+/// assert_eq!(format!("{:>4}", display_or("ab", "—")), "  ab");
+/// ```
+pub fn display_or<T, P>(value: T, placeholder: P) -> DisplayOr<T, P>
+where
+    T: IsEmpty + fmt::Display,
+    P: fmt::Display,
+{
+    DisplayOr {
+        value,
+        placeholder,
+    }
+}
+
+/// Formatting adapter returned by [`display_or`]
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayOr<T, P> {
+    value: T,
+    placeholder: P,
+}
+
+impl<T, P> fmt::Display for DisplayOr<T, P>
+where
+    T: IsEmpty + fmt::Display,
+    P: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.is_empty() {
+            self.placeholder.fmt(f)
+        } else {
+            self.value.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::display_or;
+
+    #[test]
+    fn placeholder() {
+        assert_eq!(display_or("", "N/A").to_string(), "N/A");
+        assert_eq!(display_or(&String::from("value"), "N/A").to_string(), "value");
+        assert_eq!(format!("[{:<5}]", display_or(String::new(), '-')), "[-    ]");
+    }
+}
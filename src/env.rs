@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Environment variable lookups treating empty variables as missing
+//!
+//! `MY_VAR= program` unsets a value for most intents and purposes but [`std::env::var`] still
+//! returns it as `Ok("")`. The functions in this module treat unset *and* empty variables alike.
+//!
+//! [`std::env::var`]: https://doc.rust-lang.org/std/env/fn.var.html
+//!
+//! # Examples
+//!
+//! ```
+//! std::env::set_var("IF_EMPTY_DOC_EXAMPLE", "");
+//! assert_eq!(if_empty::env::var_or("IF_EMPTY_DOC_EXAMPLE", "default"), "default");
+//! ```
+
+use std::ffi::{OsStr, OsString};
+
+/// Returns the value of `key` if it is set, valid unicode and not empty
+pub fn var<K: AsRef<OsStr>>(key: K) -> Option<String> {
+    var_with(|key| std::env::var_os(key), key.as_ref())
+}
+
+/// Returns the value of `key` or `default` if it is unset, not valid unicode or empty
+pub fn var_or<K: AsRef<OsStr>, V: Into<String>>(key: K, default: V) -> String {
+    var(key).unwrap_or_else(|| default.into())
+}
+
+/// Returns the value of `key` or the result of `f` if it is unset, not valid unicode or empty
+pub fn var_or_else<K: AsRef<OsStr>, F: FnOnce() -> String>(key: K, f: F) -> String {
+    var(key).unwrap_or_else(f)
+}
+
+/// Returns the value of `key` if it is set and not empty
+pub fn var_os<K: AsRef<OsStr>>(key: K) -> Option<OsString> {
+    var_os_with(|key| std::env::var_os(key), key.as_ref())
+}
+
+/// Returns the value of `key` or `default` if it is unset or empty
+pub fn var_os_or<K: AsRef<OsStr>, V: Into<OsString>>(key: K, default: V) -> OsString {
+    var_os(key).unwrap_or_else(|| default.into())
+}
+
+/// Looks up `key` with `get` and returns it if it is valid unicode and not empty
+fn var_with<G: FnOnce(&OsStr) -> Option<OsString>>(get: G, key: &OsStr) -> Option<String> {
+    var_os_with(get, key).and_then(|value| value.into_string().ok())
+}
+
+/// Looks up `key` with `get` and returns it if it is not empty
+fn var_os_with<G: FnOnce(&OsStr) -> Option<OsString>>(get: G, key: &OsStr) -> Option<OsString> {
+    get(key).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{OsStr, OsString};
+
+    use super::{var_os_with, var_with};
+    use crate::env;
+
+    /// Stands in for the process environment, so tests don't race on `set_var`
+    fn fake(key: &OsStr) -> Option<OsString> {
+        match key.to_str()? {
+            "SET" => Some(OsString::from("value")),
+            "EMPTY" => Some(OsString::new()),
+            #[cfg(unix)]
+            "NOT_UNICODE" => {
+                use std::os::unix::ffi::OsStringExt;
+
+                Some(OsString::from_vec(vec![0xff]))
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn var() {
+        assert_eq!(var_with(fake, OsStr::new("SET")), Some("value".to_string()));
+        assert_eq!(var_with(fake, OsStr::new("EMPTY")), None);
+        assert_eq!(var_with(fake, OsStr::new("UNSET")), None);
+        #[cfg(unix)]
+        assert_eq!(var_with(fake, OsStr::new("NOT_UNICODE")), None);
+    }
+
+    #[test]
+    fn var_os() {
+        assert_eq!(var_os_with(fake, OsStr::new("SET")), Some(OsString::from("value")));
+        assert_eq!(var_os_with(fake, OsStr::new("EMPTY")), None);
+        assert_eq!(var_os_with(fake, OsStr::new("UNSET")), None);
+        #[cfg(unix)]
+        assert!(var_os_with(fake, OsStr::new("NOT_UNICODE")).is_some());
+    }
+
+    #[test]
+    fn fallbacks() {
+        // Only read, never set by any test
+        let unset = "IF_EMPTY_TEST_VAR_NEVER_SET";
+        assert_eq!(env::var(unset), None);
+        assert_eq!(env::var_or(unset, "default"), "default");
+        assert_eq!(env::var_or_else(unset, || "default".to_string()), "default");
+        assert_eq!(env::var_os_or(unset, "default"), OsString::from("default"));
+    }
+}
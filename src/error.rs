@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{any, error::Error, fmt};
+
+/// Returned when an empty value is rejected
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::EmptyError;
+///
+/// let error = EmptyError::new::<Vec<u8>>();
+/// assert_eq!(error.type_name(), std::any::type_name::<Vec<u8>>());
+/// assert!(error.to_string().ends_with("is empty"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmptyError {
+    type_name: &'static str,
+}
+
+impl EmptyError {
+    /// Creates an error for an empty value of type `T`
+    pub fn new<T: ?Sized>() -> Self {
+        Self {
+            type_name: any::type_name::<T>(),
+        }
+    }
+
+    /// Returns the name of the type whose value was empty
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value of type `{}` is empty", self.type_name)
+    }
+}
+
+impl Error for EmptyError {}
+
+/// Returned by [`EmptyReport::assert_non_empty`] listing the empty fields
+///
+/// [`EmptyReport::assert_non_empty`]: crate::EmptyReport::assert_non_empty
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::EmptyFieldsError;
+///
+/// let error = EmptyFieldsError::new(vec!["name".to_string(), "server.host".to_string()]);
+/// assert_eq!(error.fields(), ["name", "server.host"]);
+/// assert_eq!(error.to_string(), "empty fields: name, server.host");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyFieldsError {
+    fields: Vec<String>,
+}
+
+impl EmptyFieldsError {
+    /// Creates an error for the empty `fields`
+    pub fn new(fields: Vec<String>) -> Self {
+        Self {
+            fields,
+        }
+    }
+
+    /// Returns the paths of the empty fields
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+
+    /// Consumes the error and returns the paths of the empty fields
+    pub fn into_fields(self) -> Vec<String> {
+        self.fields
+    }
+}
+
+impl fmt::Display for EmptyFieldsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "empty fields: {}", self.fields.join(", "))
+    }
+}
+
+impl Error for EmptyFieldsError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{EmptyError, EmptyFieldsError};
+
+    #[test]
+    fn error() {
+        let error = EmptyError::new::<Vec<u8>>();
+        assert_eq!(error.type_name(), std::any::type_name::<Vec<u8>>());
+        assert_eq!(
+            error.to_string(),
+            format!("value of type `{}` is empty", std::any::type_name::<Vec<u8>>())
+        );
+
+        let boxed: Box<dyn Error> = Box::new(error);
+        assert!(boxed.source().is_none());
+    }
+
+    #[test]
+    fn fields_error() {
+        let error = EmptyFieldsError::new(vec!["user".to_string()]);
+        assert_eq!(error.fields(), ["user"]);
+        assert_eq!(error.to_string(), "empty fields: user");
+        assert_eq!(error.clone().into_fields(), ["user"]);
+
+        let boxed: Box<dyn Error> = Box::new(error);
+        assert!(boxed.source().is_none());
+    }
+}
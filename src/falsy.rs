@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// For checking IfFalsy on values with scripting style truthiness
+///
+/// Useful for data from dynamically typed sources, e.g. JSON, where `""`, `0`, `false` and `null`
+/// all mean "not set". The policy is intentionally broader than emptiness, so this trait is not
+/// part of the prelude and has to be imported explicitly.
+///
+/// | Type | Falsy values |
+/// |------|--------------|
+/// | `&str`, `String` | `""` |
+/// | `Vec<T>` | `[]` |
+/// | `bool` | `false` |
+/// | integers | `0` |
+/// | `f32`, `f64` | `0.0`, `-0.0` and NaN |
+/// | `Option<T>` | `None` |
+///
+/// Whitespace is not falsy, see [`IfBlank`] for that.
+///
+/// [`IfBlank`]: crate::IfBlank
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfFalsy;
+///
+/// assert_eq!(0.if_falsy(8080), 8080);
+/// assert!(false.if_falsy(true));
+/// assert_eq!(None.if_falsy(Some("default")), Some("default"));
+/// assert_eq!(Some(0).if_falsy(Some(1)), Some(0));
+/// ```
+pub trait IfFalsy: Sized {
+    /// Returns `true` if `self` is falsy
+    fn is_falsy(&self) -> bool;
+
+    /// Returns `val` if `self` is falsy
+    fn if_falsy(self, val: Self) -> Self {
+        if self.is_falsy() {
+            val
+        } else {
+            self
+        }
+    }
+}
+
+/// Implementation of `IfFalsy` for [`str`]
+///
+/// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
+impl IfFalsy for &str {
+    fn is_falsy(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implementation of `IfFalsy` for [`String`]
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+impl IfFalsy for String {
+    fn is_falsy(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implementation of `IfFalsy` for [`Vec`]
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+impl<T> IfFalsy for Vec<T> {
+    fn is_falsy(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Implementation of `IfFalsy` for [`bool`]
+///
+/// [`bool`]: https://doc.rust-lang.org/std/primitive.bool.html
+impl IfFalsy for bool {
+    fn is_falsy(&self) -> bool {
+        !*self
+    }
+}
+
+/// Implementation of `IfFalsy` for [`Option`], only `None` is falsy
+///
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+impl<T> IfFalsy for Option<T> {
+    fn is_falsy(&self) -> bool {
+        self.is_none()
+    }
+}
+
+macro_rules! if_falsy_int {
+    ($($type:ty),*) => {
+        $(
+            /// Implementation of `IfFalsy` for
+            #[doc = concat!("[`", stringify!($type), "`]")]
+            impl IfFalsy for $type {
+                fn is_falsy(&self) -> bool {
+                    *self == 0
+                }
+            }
+        )*
+    };
+}
+
+if_falsy_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! if_falsy_float {
+    ($($type:ty),*) => {
+        $(
+            /// Implementation of `IfFalsy` for
+            #[doc = concat!("[`", stringify!($type), "`]")]
+            impl IfFalsy for $type {
+                fn is_falsy(&self) -> bool {
+                    *self == 0.0 || self.is_nan()
+                }
+            }
+        )*
+    };
+}
+
+if_falsy_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::IfFalsy;
+
+    #[test]
+    fn strings() {
+        assert_eq!("".if_falsy("fallback"), "fallback");
+        assert_eq!(" ".if_falsy("fallback"), " ");
+        assert_eq!(String::new().if_falsy("fallback".to_string()), "fallback");
+        assert_eq!(Vec::<u8>::new().if_falsy(vec![1]), [1]);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(0u16.if_falsy(8080), 8080);
+        assert_eq!((-1i32).if_falsy(1), -1);
+        assert_eq!((-0.0f64).if_falsy(1.5), 1.5);
+        assert_eq!(f32::NAN.if_falsy(1.5), 1.5);
+        assert_eq!(f64::INFINITY.if_falsy(1.5), f64::INFINITY);
+    }
+
+    #[test]
+    fn bool() {
+        assert!(false.if_falsy(true));
+        assert!(!false.if_falsy(false));
+        assert!(true.if_falsy(false));
+    }
+
+    #[test]
+    fn option() {
+        assert_eq!(None.if_falsy(Some(1)), Some(1));
+        assert_eq!(Some(0).if_falsy(Some(1)), Some(0));
+    }
+}
@@ -0,0 +1,195 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{borrow::Cow, ffi::OsString, path::PathBuf};
+
+use crate::IsEmpty;
+
+/// For filling the empty parts of a value from borrowed defaults
+///
+/// In contrast to [`MergeEmpty`] the defaults are borrowed, so a single set of defaults can be applied to many
+/// values, e.g. builders right before calling `build()`. Use `#[derive(FillDefaults)]` to fill structs field by
+/// field.
+///
+/// [`MergeEmpty`]: crate::MergeEmpty
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::FillDefaults;
+///
+/// #[derive(FillDefaults, Default)]
+/// struct RequestBuilder {
+///     method: String,
+///     headers: Vec<(String, String)>,
+///     timeout: Option<u32>,
+/// }
+///
+/// let defaults = RequestBuilder {
+///     method: "GET".to_string(),
+///     headers: vec![("Accept".to_string(), "*/*".to_string())],
+///     timeout: Some(30),
+/// };
+///
+/// let builder = RequestBuilder {
+///     timeout: Some(5),
+///     ..RequestBuilder::default()
+/// }
+/// .fill_defaults(&defaults);
+/// assert_eq!(builder.method, "GET");
+/// assert_eq!(builder.headers.len(), 1);
+/// assert_eq!(builder.timeout, Some(5));
+/// ```
+pub trait FillDefaults {
+    /// Returns `self` with its empty parts filled from `defaults`
+    fn fill_defaults(self, defaults: &Self) -> Self;
+}
+
+macro_rules! fill_defaults_if_empty {
+    ($([$($generics:tt)*] $type:ty),*) => {
+        $(
+            impl<$($generics)*> FillDefaults for $type {
+                /// Returns a clone of `defaults` if `self` is empty.
+                /// Otherwise `self` is returned.
+                fn fill_defaults(self, defaults: &Self) -> Self {
+                    if IsEmpty::is_empty(&self) {
+                        defaults.clone()
+                    } else {
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+fill_defaults_if_empty!(
+    [] String,
+    [] OsString,
+    [] PathBuf,
+    [T: Clone] Vec<T>,
+    [B: IsEmpty + ToOwned + ?Sized] Cow<'_, B>,
+    [T: IsEmpty + Clone, const N: usize] [T; N]
+);
+
+#[cfg(feature = "numeric")]
+fill_defaults_if_empty!([] std::time::Duration);
+
+macro_rules! fill_defaults_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: IsEmpty + Clone),+> FillDefaults for ($($name,)+) {
+            /// Returns a clone of `defaults` if all elements of `self` are empty.
+            /// Otherwise `self` is returned.
+            fn fill_defaults(self, defaults: &Self) -> Self {
+                if IsEmpty::is_empty(&self) {
+                    defaults.clone()
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+fill_defaults_tuple!(A);
+fill_defaults_tuple!(A, B);
+fill_defaults_tuple!(A, B, C);
+fill_defaults_tuple!(A, B, C, D);
+fill_defaults_tuple!(A, B, C, D, E);
+fill_defaults_tuple!(A, B, C, D, E, F);
+fill_defaults_tuple!(A, B, C, D, E, F, G);
+fill_defaults_tuple!(A, B, C, D, E, F, G, H);
+
+/// Implementation of `FillDefaults` for [`Option`]
+///
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+impl<T: Clone> FillDefaults for Option<T> {
+    /// Returns a clone of `defaults` if `self` is `None`.
+    /// Otherwise `self` is returned.
+    fn fill_defaults(self, defaults: &Self) -> Self {
+        self.or_else(|| defaults.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, ffi::OsString, path::PathBuf};
+
+    use crate::FillDefaults;
+
+    #[test]
+    fn fill_defaults() {
+        assert_eq!(String::new().fill_defaults(&"default".to_string()), "default");
+        assert_eq!("value".to_string().fill_defaults(&"default".to_string()), "value");
+        assert_eq!(
+            OsString::new().fill_defaults(&OsString::from("default")),
+            OsString::from("default")
+        );
+        assert_eq!(Vec::new().fill_defaults(&vec![1]), vec![1]);
+        assert_eq!(None.fill_defaults(&Some(1)), Some(1));
+        assert_eq!(Some(2).fill_defaults(&Some(1)), Some(2));
+        assert_eq!(
+            PathBuf::new().fill_defaults(&PathBuf::from("default")),
+            PathBuf::from("default")
+        );
+        assert_eq!(Cow::Borrowed("").fill_defaults(&Cow::Borrowed("default")), "default");
+        assert_eq!([""].fill_defaults(&["default"]), ["default"]);
+        assert_eq!(("", "").fill_defaults(&("a", "b")), ("a", "b"));
+    }
+
+    #[test]
+    fn derive() {
+        #[derive(FillDefaults, Debug, PartialEq)]
+        struct Builder {
+            name: String,
+            tags: Vec<String>,
+            retries: Option<u8>,
+        }
+
+        #[derive(FillDefaults, Debug, PartialEq)]
+        struct Tuple(String, Option<u8>);
+
+        let defaults = Builder {
+            name: "default".to_string(),
+            tags: vec!["tag".to_string()],
+            retries: Some(3),
+        };
+        let builder = Builder {
+            name: String::new(),
+            tags: Vec::new(),
+            retries: Some(1),
+        };
+        assert_eq!(
+            builder.fill_defaults(&defaults),
+            Builder {
+                name: "default".to_string(),
+                tags: vec!["tag".to_string()],
+                retries: Some(1),
+            }
+        );
+        assert_eq!(
+            Tuple("value".to_string(), None).fill_defaults(&Tuple(String::new(), Some(1))),
+            Tuple("value".to_string(), Some(1))
+        );
+    }
+
+    #[test]
+    fn derive_generics() {
+        #[derive(FillDefaults, Debug, PartialEq)]
+        struct Page<T, const N: usize> {
+            items: Vec<T>,
+            columns: [String; N],
+        }
+
+        let defaults = Page {
+            items: vec![1],
+            columns: ["a".to_string(), "b".to_string()],
+        };
+        let page = Page {
+            items: Vec::new(),
+            columns: [String::new(), String::new()],
+        };
+        assert_eq!(page.fill_defaults(&defaults), defaults);
+    }
+}
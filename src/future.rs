@@ -0,0 +1,136 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Asynchronously computed fallbacks
+//!
+//! # Examples
+//!
+//! ```
+//! use if_empty::IfEmptyAsync;
+//!
+//! async fn fetch_default() -> String {
+//!     "from service".to_string()
+//! }
+//!
+//! async fn host(cli: String) -> String {
+//!     cli.if_empty_with_async(fetch_default).await
+//! }
+//! ```
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::IsEmpty;
+
+/// Extension methods for computing fallbacks with futures
+pub trait IfEmptyAsync: IsEmpty + Sized {
+    /// Returns the output of the future created by `f` if `self` is empty
+    ///
+    /// `f` is only called if `self` is empty.
+    fn if_empty_with_async<F, Fut>(self, f: F) -> IfEmptyWithAsync<Self, F, Fut>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Self>,
+    {
+        IfEmptyWithAsync {
+            state: State::Init(Some((self, f))),
+        }
+    }
+}
+
+impl<T: IsEmpty> IfEmptyAsync for T {}
+
+/// Future returned by [`IfEmptyAsync::if_empty_with_async`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct IfEmptyWithAsync<T, F, Fut> {
+    state: State<T, F, Fut>,
+}
+
+enum State<T, F, Fut> {
+    Init(Option<(T, F)>),
+    Pending(Pin<Box<Fut>>),
+}
+
+// `T` and `F` are never pinned and `Fut` is pinned on the heap
+impl<T, F, Fut> Unpin for IfEmptyWithAsync<T, F, Fut> {}
+
+impl<T, F, Fut> Future for IfEmptyWithAsync<T, F, Fut>
+where
+    T: IsEmpty,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = T>,
+{
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        if let State::Init(init) = &mut self.state {
+            let (value, f) = init.take().expect("`IfEmptyWithAsync` polled after completion");
+            if !value.is_empty() {
+                return Poll::Ready(value);
+            }
+            self.state = State::Pending(Box::pin(f()));
+        }
+        match &mut self.state {
+            State::Pending(future) => future.as_mut().poll(cx),
+            State::Init(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T, F, Fut> fmt::Debug for IfEmptyWithAsync<T, F, Fut> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IfEmptyWithAsync").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+    };
+
+    use crate::IfEmptyAsync;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn if_empty_with_async() {
+        assert_eq!(
+            block_on(String::new().if_empty_with_async(|| async { "text".to_string() })),
+            "text"
+        );
+        assert_eq!(block_on(vec![1].if_empty_with_async(|| async { unreachable!() })), vec![1]);
+    }
+
+    #[test]
+    fn lazy() {
+        let future = String::new().if_empty_with_async(|| -> std::future::Ready<String> { unreachable!() });
+        drop(future);
+    }
+}
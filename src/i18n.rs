@@ -0,0 +1,142 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Placeholders for empty values in the language of the user
+//!
+//! Placeholders are registered per locale, either as fixed text or as provider function which
+//! can look up a message in a translation system like fluent. Lookups fall back from a regional
+//! locale like `de-AT` to its language `de` and finally to [`NOT_AVAILABLE`].
+//!
+//! [`NOT_AVAILABLE`]: crate::placeholders::NOT_AVAILABLE
+//!
+//! # Examples
+//!
+//! ```
+//! use if_empty::i18n::{display_or_localized, register_placeholder};
+//!
+//! register_placeholder("de", "k. A.");
+//! assert_eq!(display_or_localized("", "de-AT").to_string(), "k. A.");
+//! assert_eq!(display_or_localized("", "fr").to_string(), "N/A");
+//! assert_eq!(display_or_localized("Wien", "de").to_string(), "Wien");
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+use crate::{placeholders::NOT_AVAILABLE, IsEmpty};
+
+type Provider = Arc<dyn Fn() -> String + Send + Sync>;
+
+static PROVIDERS: RwLock<BTreeMap<String, Provider>> = RwLock::new(BTreeMap::new());
+
+/// Registers `placeholder` as text shown for empty values in `locale`
+///
+/// Replaces a previously registered placeholder or provider of `locale`.
+pub fn register_placeholder<L: Into<String>, P: Into<String>>(locale: L, placeholder: P) {
+    let placeholder = placeholder.into();
+    register_provider(locale, move || placeholder.clone());
+}
+
+/// Registers `provider` computing the text shown for empty values in `locale`
+///
+/// Replaces a previously registered placeholder or provider of `locale`. `provider` is called
+/// every time an empty value is displayed, so it can follow reloaded translations.
+pub fn register_provider<L, F>(locale: L, provider: F)
+where
+    L: Into<String>,
+    F: Fn() -> String + Send + Sync + 'static,
+{
+    PROVIDERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(locale.into(), Arc::new(provider));
+}
+
+/// Returns the placeholder registered for `locale`, its language or [`NOT_AVAILABLE`]
+///
+/// [`NOT_AVAILABLE`]: crate::placeholders::NOT_AVAILABLE
+pub fn placeholder_for(locale: &str) -> String {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    let provider = {
+        let providers = PROVIDERS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+        providers.get(locale).or_else(|| providers.get(language)).cloned()
+    };
+    // Called without holding the lock, so providers can register placeholders themselves
+    match provider {
+        Some(provider) => provider(),
+        None => NOT_AVAILABLE.to_string(),
+    }
+}
+
+/// Displays the placeholder of `locale` instead of `value` if `value` is empty
+///
+/// The placeholder is only looked up if `value` is empty when it is displayed.
+pub fn display_or_localized<T>(value: T, locale: &str) -> DisplayOrLocalized<'_, T>
+where
+    T: IsEmpty + fmt::Display,
+{
+    DisplayOrLocalized {
+        value,
+        locale,
+    }
+}
+
+/// Formatting adapter returned by [`display_or_localized`]
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayOrLocalized<'a, T> {
+    value: T,
+    locale: &'a str,
+}
+
+impl<T> fmt::Display for DisplayOrLocalized<'_, T>
+where
+    T: IsEmpty + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.is_empty() {
+            f.pad(&placeholder_for(self.locale))
+        } else {
+            self.value.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::{display_or_localized, placeholder_for, register_placeholder, register_provider};
+
+    #[test]
+    fn fallback() {
+        register_placeholder("test-fallback", "text");
+        assert_eq!(placeholder_for("test-fallback"), "text");
+        assert_eq!(placeholder_for("test"), "N/A");
+        assert_eq!(placeholder_for("unregistered"), "N/A");
+    }
+
+    #[test]
+    fn language() {
+        register_placeholder("tl", "wala");
+        assert_eq!(placeholder_for("tl-PH"), "wala");
+        assert_eq!(placeholder_for("tl_PH"), "wala");
+        assert_eq!(format!("[{:>5}]", display_or_localized(String::new(), "tl")), "[ wala]");
+    }
+
+    #[test]
+    fn provider() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        register_provider("eo", || {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            "neniu".to_string()
+        });
+        assert_eq!(display_or_localized("saluton", "eo").to_string(), "saluton");
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+        assert_eq!(display_or_localized("", "eo").to_string(), "neniu");
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+}
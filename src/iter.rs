@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Iterator adapters for handling empty items
+//!
+//! # Examples
+//!
+//! ```
+//! use if_empty::IteratorExt;
+//!
+//! let cells: Vec<String> = "a,,c".split(',').map(String::from).fill_empty("-".to_string()).collect();
+//! assert_eq!(cells, ["a", "-", "c"]);
+//! ```
+
+use crate::IsEmpty;
+
+/// Extension methods for iterators
+pub trait IteratorExt: Iterator + Sized {
+    /// Replaces empty items with clones of `fallback`
+    fn fill_empty(self, fallback: Self::Item) -> FillEmpty<Self>
+    where
+        Self::Item: IsEmpty + Clone,
+    {
+        FillEmpty {
+            iter: self,
+            fallback,
+        }
+    }
+
+    /// Replaces empty items with the result of `f`
+    ///
+    /// `f` is only called for empty items.
+    fn fill_empty_with<F>(self, f: F) -> FillEmptyWith<Self, F>
+    where
+        Self::Item: IsEmpty,
+        F: FnMut() -> Self::Item,
+    {
+        FillEmptyWith {
+            iter: self,
+            f,
+        }
+    }
+
+    /// Yields the items of the iterator or only `default` if the iterator is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IteratorExt;
+    ///
+    /// let results: Vec<&str> = Vec::new();
+    /// let rows: Vec<_> = results.into_iter().default_if_empty("no results").collect();
+    /// assert_eq!(rows, ["no results"]);
+    /// ```
+    fn default_if_empty(self, default: Self::Item) -> DefaultIfEmpty<Self> {
+        DefaultIfEmpty {
+            iter: self,
+            default: Some(default),
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Iterator returned by [`IteratorExt::fill_empty`]
+#[derive(Debug, Clone)]
+pub struct FillEmpty<I: Iterator> {
+    iter: I,
+    fallback: I::Item,
+}
+
+impl<I> Iterator for FillEmpty<I>
+where
+    I: Iterator,
+    I::Item: IsEmpty + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        if item.is_empty() {
+            Some(self.fallback.clone())
+        } else {
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator returned by [`IteratorExt::fill_empty_with`]
+#[derive(Debug, Clone)]
+pub struct FillEmptyWith<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> Iterator for FillEmptyWith<I, F>
+where
+    I: Iterator,
+    I::Item: IsEmpty,
+    F: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        if item.is_empty() {
+            Some((self.f)())
+        } else {
+            Some(item)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator returned by [`IteratorExt::default_if_empty`]
+#[derive(Debug, Clone)]
+pub struct DefaultIfEmpty<I: Iterator> {
+    iter: I,
+    default: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for DefaultIfEmpty<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                self.default = None;
+                Some(item)
+            }
+            None => self.default.take(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.default.is_some() {
+            (lower.max(1), upper.map(|upper| upper.max(1)))
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn fill_empty() {
+        let filled: Vec<_> = vec!["a", "", "c", ""].into_iter().fill_empty("-").collect();
+        assert_eq!(filled, ["a", "-", "c", "-"]);
+    }
+
+    #[test]
+    fn fill_empty_with() {
+        let mut calls = 0;
+        let filled: Vec<String> = vec![String::new(), "b".to_string(), String::new()]
+            .into_iter()
+            .fill_empty_with(|| {
+                calls += 1;
+                format!("default {}", calls)
+            })
+            .collect();
+        assert_eq!(filled, ["default 1", "b", "default 2"]);
+    }
+
+    #[test]
+    fn lazy() {
+        let mut iter = vec![vec![1], Vec::new()]
+            .into_iter()
+            .fill_empty_with(|| panic!("evaluated too early"));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some(vec![1]));
+    }
+
+    #[test]
+    fn default_if_empty() {
+        let empty: Vec<u8> = Vec::new();
+        let mut iter = empty.into_iter().default_if_empty(0);
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), None);
+
+        let items: Vec<_> = vec![1, 2].into_iter().default_if_empty(0).collect();
+        assert_eq!(items, [1, 2]);
+    }
+}
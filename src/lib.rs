@@ -78,20 +78,709 @@
 //! }
 //! ```
 
-pub use if_empty_derive::IfEmpty;
+// Allows the derive macros to refer to `::if_empty` from within this crate as well. Public so
+// the prelude can re-export the `if_empty!` macro, which shares the name.
+#[doc(hidden)]
+pub extern crate self as if_empty;
+
+use std::borrow::Cow;
+
+pub use if_empty_derive::{EmptyReport, FillDefaults, IfEmpty, MergeEmpty};
+
+pub use crate::{
+    blank::IfBlank,
+    chain::FallbackChain,
+    collection::{IfAllEmpty, RetainNonEmpty},
+    context::ContextDefault,
+    default::IfDefault,
+    display::{display_or, DisplayOr},
+    error::{EmptyError, EmptyFieldsError},
+    falsy::IfFalsy,
+    fill::FillDefaults,
+    iter::IteratorExt,
+    map::MapExt,
+    merge::MergeEmpty,
+    mutable::IfEmptyMut,
+    net::IfUnspecified,
+    non_empty::{NonEmpty, NonEmptyString, NonEmptyVec},
+    option::OptionExt,
+    path::IfEmptyPath,
+    placeholders::{IfEmptyPlaceholder, Placeholder},
+    replaced::Replaced,
+    report::EmptyReport,
+    slice::SliceExt,
+};
+
+#[macro_use]
+mod macros;
+
+#[doc(hidden)]
+pub use crate::macros::__private;
+
+pub mod env;
+#[cfg(feature = "async")]
+pub mod future;
+#[cfg(feature = "i18n")]
+pub mod i18n;
+pub mod iter;
+pub mod placeholders;
+pub mod prelude;
+#[cfg(feature = "stats")]
+pub mod stats;
+
+mod blank;
+mod chain;
+mod collection;
+mod context;
+mod default;
+mod display;
+mod error;
+mod falsy;
+mod fill;
+mod map;
+mod merge;
+mod mutable;
+mod net;
+mod non_empty;
+#[cfg(feature = "numeric")]
+mod numeric;
+mod option;
+mod path;
+mod replaced;
+mod report;
+mod slice;
+
+#[cfg(feature = "async")]
+pub use crate::future::IfEmptyAsync;
+#[cfg(feature = "i18n")]
+pub use crate::i18n::display_or_localized;
+#[cfg(feature = "numeric")]
+pub use crate::numeric::IfNan;
+
+/// For checking whether a value is empty
+#[cfg_attr(
+    feature = "diagnostic",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `IsEmpty`",
+        label = "emptiness of `{Self}` can't be checked",
+        note = "the implementors of `IsEmpty` are listed in its documentation and in the \"Provided types\" section of the README",
+        note = "implement `IsEmpty` for your own types by forwarding to their `is_empty` method"
+    )
+)]
+pub trait IsEmpty {
+    /// Returns `true` if `self` is empty
+    fn is_empty(&self) -> bool;
+
+    /// Returns the result of `f` if `self` is empty
+    ///
+    /// `f` is only called if `self` is empty.
+    fn if_empty_with<F: FnOnce() -> Self>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            f()
+        } else {
+            self
+        }
+    }
+
+    /// Returns the result of `f` called with `ctx` if `self` is empty
+    ///
+    /// `f` is only called if `self` is empty. Passing the context as an argument avoids capturing
+    /// it in the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IsEmpty;
+    ///
+    /// struct Request {
+    ///     accept_language: &'static str,
+    /// }
+    ///
+    /// let request = Request {
+    ///     accept_language: "de",
+    /// };
+    /// let locale = String::new().if_empty_with_context(&request, |request| request.accept_language.to_string());
+    /// assert_eq!(locale, "de");
+    /// ```
+    fn if_empty_with_context<C: ?Sized, F: FnOnce(&C) -> Self>(self, ctx: &C, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            f(ctx)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the result of the fallible `f` if `self` is empty
+    ///
+    /// `f` is only called if `self` is empty and its error is propagated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IsEmpty;
+    ///
+    /// let host = "example.com".to_string().try_if_empty_with(|| std::env::var("IF_EMPTY_DOC_UNSET_HOST"));
+    /// assert_eq!(host.unwrap(), "example.com");
+    ///
+    /// let host = String::new().try_if_empty_with(|| std::env::var("IF_EMPTY_DOC_UNSET_HOST"));
+    /// assert!(host.is_err());
+    /// ```
+    fn try_if_empty_with<E, F: FnOnce() -> Result<Self, E>>(self, f: F) -> Result<Self, E>
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            f()
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Returns `val` if `self` is empty
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmptyError`] if the fallback is empty as well, so a misconfigured default
+    /// can't propagate an empty value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IsEmpty;
+    ///
+    /// assert_eq!(String::new().if_empty_checked("default".to_string()).unwrap(), "default");
+    /// assert!(String::new().if_empty_checked(String::new()).is_err());
+    /// ```
+    fn if_empty_checked(self, val: Self) -> Result<Self, EmptyError>
+    where
+        Self: Sized,
+    {
+        if !self.is_empty() {
+            Ok(self)
+        } else if val.is_empty() {
+            Err(EmptyError::new::<Self>())
+        } else {
+            Ok(val)
+        }
+    }
+
+    /// Returns `val` if `self` is empty, together with whether it was used
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::{IsEmpty, Replaced};
+    ///
+    /// let (host, replaced) = String::new().if_empty_reporting("localhost".to_string());
+    /// assert_eq!(host, "localhost");
+    /// assert_eq!(replaced, Replaced::Yes);
+    /// ```
+    fn if_empty_reporting(self, val: Self) -> (Self, Replaced)
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            (val, Replaced::Yes)
+        } else {
+            (self, Replaced::No)
+        }
+    }
+
+    /// Calls `f` if `self` is empty and returns `self` unchanged
+    ///
+    /// Useful for adding logging or metrics without altering the fallback behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::{IfEmpty, IsEmpty};
+    ///
+    /// let mut misses = 0;
+    /// let name = String::new().inspect_if_empty(|| misses += 1).if_empty("default".to_string());
+    /// assert_eq!(name, "default");
+    /// assert_eq!(misses, 1);
+    /// ```
+    fn inspect_if_empty<F: FnOnce()>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            f();
+        }
+        self
+    }
+
+    /// Returns the result of `f` if `self` is not empty
+    ///
+    /// Empty values are returned unchanged and `f` is not called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IsEmpty;
+    ///
+    /// let prefix = |path: String| format!("/{}", path);
+    /// assert_eq!("docs".to_string().map_if_not_empty(prefix), "/docs");
+    /// assert_eq!(String::new().map_if_not_empty(prefix), "");
+    /// ```
+    fn map_if_not_empty<F: FnOnce(Self) -> Self>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            self
+        } else {
+            f(self)
+        }
+    }
+
+    /// Returns `None` if `self` is empty.
+    /// Otherwise `Some(self)` is returned.
+    fn empty_to_none(self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Returns `Err(err)` if `self` is empty.
+    /// Otherwise `Ok(self)` is returned.
+    fn ok_or_empty<E>(self, err: E) -> Result<Self, E>
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            Err(err)
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Returns `Err(f())` if `self` is empty.
+    /// Otherwise `Ok(self)` is returned.
+    fn ok_or_empty_else<E, F: FnOnce() -> E>(self, f: F) -> Result<Self, E>
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            Err(f())
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Returns `self` if it is not empty
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` and the name of the type if `self` is empty.
+    #[track_caller]
+    fn expect_not_empty(self, msg: &str) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            panic!("{}: {}", msg, EmptyError::new::<Self>());
+        }
+        self
+    }
+
+    /// Returns `self` if it is not empty
+    ///
+    /// # Panics
+    ///
+    /// Panics with the name of the type if `self` is empty.
+    #[track_caller]
+    fn unwrap_not_empty(self) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_empty() {
+            panic!("called `unwrap_not_empty()` on an empty value: {}", EmptyError::new::<Self>());
+        }
+        self
+    }
+
+    /// Returns `None` if `self` is empty.
+    /// Otherwise `Some(&self)` is returned.
+    fn as_non_empty(&self) -> Option<&Self> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+/// Returns `fallback` if `value` is empty
+///
+/// Free function version of [`IfEmpty::if_empty`] for pipeline style code and types where method
+/// resolution is ambiguous.
+///
+/// # Examples
+///
+/// ```
+/// let names: Vec<String> = vec![String::new(), "ferris".to_string()]
+///     .into_iter()
+///     .map(|name| if_empty::if_empty(name, "anonymous".to_string()))
+///     .collect();
+/// assert_eq!(names, ["anonymous", "ferris"]);
+/// ```
+pub fn if_empty<T: IfEmpty>(value: T, fallback: T) -> T {
+    value.if_empty(fallback)
+}
+
+/// Returns the result of `f` if `value` is empty
+///
+/// Free function version of [`IsEmpty::if_empty_with`]. `f` is only called if `value` is empty.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(if_empty::if_empty_with(String::new(), || "generated".to_string()), "generated");
+/// ```
+pub fn if_empty_with<T: IsEmpty, F: FnOnce() -> T>(value: T, f: F) -> T {
+    value.if_empty_with(f)
+}
+
+/// Returns `fallback` if `s` is empty
+///
+/// Usable in `const` contexts, e.g. to apply defaults to configuration tables at build time.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::if_empty_const;
+///
+/// const USER: &str = if_empty_const(
+///     match option_env!("IF_EMPTY_DOC_UNSET_USER") {
+///         Some(user) => user,
+///         None => "",
+///     },
+///     "root",
+/// );
+/// assert_eq!(USER, "root");
+/// ```
+pub const fn if_empty_const<'a>(s: &'a str, fallback: &'a str) -> &'a str {
+    if s.is_empty() {
+        fallback
+    } else {
+        s
+    }
+}
+
+/// Returns the first non-empty candidate
+///
+/// Candidates are only consumed until a non-empty one is found.
+///
+/// # Examples
+///
+/// ```
+/// let cli = String::new();
+/// let env = std::env::var("IF_EMPTY_DOC_HOST").unwrap_or_default();
+/// let config = "example.com".to_string();
+/// assert_eq!(if_empty::first_non_empty(vec![cli, env, config]), Some("example.com".to_string()));
+/// ```
+pub fn first_non_empty<I>(candidates: I) -> Option<I::Item>
+where
+    I: IntoIterator,
+    I::Item: IsEmpty,
+{
+    candidates.into_iter().find(|candidate| !candidate.is_empty())
+}
+
+/// Returns the first non-empty candidate or `default` if all of them are empty
+pub fn first_non_empty_or<I>(candidates: I, default: I::Item) -> I::Item
+where
+    I: IntoIterator,
+    I::Item: IsEmpty,
+{
+    first_non_empty(candidates).unwrap_or(default)
+}
 
 /// For checking IfEmpty on value semantics
+///
+/// Of the reference types only `&Path` implements this trait. `&str` and `&OsStr` are covered by
+/// [`IfEmptyBorrowed`] instead, implementing both would make `value.if_empty(..)` ambiguous
+/// whenever both traits are in scope.
+///
+/// With the `any` feature, `IfEmptyAny` is a single trait covering owned and borrowed values in
+/// generic code.
+#[cfg_attr(
+    feature = "diagnostic",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `IfEmpty`",
+        label = "`if_empty` is not available for `{Self}`",
+        note = "owned types like `String` implement `IfEmpty`, borrowed types like `str` implement `IfEmptyBorrowed` instead",
+        note = "the implementors of `IfEmpty` are listed in its documentation and in the \"Provided types\" section of the README",
+        note = "implement `IfEmpty` for your own types or use `#[derive(IfEmpty)]` if they have an `is_empty` method"
+    )
+)]
 pub trait IfEmpty {
     /// Returns `val` if the `self` is empty
     fn if_empty(self, val: Self) -> Self;
+
+    /// Returns `val` converted into `Self` if `self` is empty
+    ///
+    /// This allows passing e.g. a `&str` for a [`String`] or an array for a [`Vec`]. The conversion
+    /// happens before the check, use [`IfEmptyRef`] to only convert if the fallback is used.
+    /// `if_empty` itself keeps taking `Self` so calls like `value.if_empty(Default::default())`
+    /// still infer their types.
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IfEmpty;
+    ///
+    /// assert_eq!(String::new().if_empty_into("default"), "default");
+    /// assert_eq!(Vec::new().if_empty_into([1, 2]), vec![1, 2]);
+    /// ```
+    fn if_empty_into<V: Into<Self>>(self, val: V) -> Self
+    where
+        Self: Sized,
+    {
+        self.if_empty(val.into())
+    }
 }
 
 /// For checking IfEmpty on borrowed objects
+///
+/// `self` and `val` share one lifetime, but longer lived fallbacks like `&'static str` constants
+/// are shortened to it automatically, so they need no separate method.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfEmptyBorrowed;
+///
+/// const ANONYMOUS: &str = "anonymous";
+///
+/// fn display_name(name: &String) -> &str {
+///     name.as_str().if_empty(ANONYMOUS)
+/// }
+///
+/// assert_eq!(display_name(&String::new()), "anonymous");
+/// assert_eq!(display_name(&"ferris".to_string()), "ferris");
+/// ```
+///
+/// With the `any` feature, `IfEmptyAny` is a single trait covering owned and borrowed values in
+/// generic code.
+#[cfg_attr(
+    feature = "diagnostic",
+    diagnostic::on_unimplemented(
+        message = "`{Self}` does not implement `IfEmptyBorrowed`",
+        label = "`if_empty` is not available for `&{Self}`",
+        note = "borrowed types like `str` implement `IfEmptyBorrowed`, owned types like `String` implement `IfEmpty` instead",
+        note = "the implementors of `IfEmptyBorrowed` are listed in its documentation and in the \"Provided types\" section of the README"
+    )
+)]
 pub trait IfEmptyBorrowed {
     /// Return `val` if `self` is empty
     fn if_empty<'a>(&'a self, val: &'a Self) -> &'a Self;
 }
 
+/// Opt-in for wrapper types to check emptiness through their [`Deref`] target
+///
+/// A wrapper is considered empty if its target is. The fallback is a reference to the target, so
+/// e.g. a `Box<str>` takes a string literal just like `str` does. Implemented for [`Box`], [`Rc`]
+/// and [`Arc`].
+///
+/// [`Deref`]: std::ops::Deref
+/// [`Rc`]: std::rc::Rc
+/// [`Arc`]: std::sync::Arc
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::Deref;
+///
+/// use if_empty::IfEmptyDeref;
+///
+/// struct UserName(String);
+///
+/// impl Deref for UserName {
+///     type Target = str;
+///
+///     fn deref(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// impl IfEmptyDeref for UserName {}
+///
+/// assert_eq!(UserName(String::new()).if_empty("anonymous"), "anonymous");
+/// assert_eq!(UserName("ferris".to_string()).if_empty("anonymous"), "ferris");
+/// ```
+pub trait IfEmptyDeref: std::ops::Deref
+where
+    Self::Target: IsEmpty,
+{
+    /// Returns `input` if the target of `self` is empty.
+    /// Otherwise the target of `self` is returned.
+    fn if_empty<'a>(&'a self, input: &'a Self::Target) -> &'a Self::Target {
+        if (**self).is_empty() {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IfEmptyDeref for Box<T> {}
+impl<T: IsEmpty + ?Sized> IfEmptyDeref for std::rc::Rc<T> {}
+impl<T: IsEmpty + ?Sized> IfEmptyDeref for std::sync::Arc<T> {}
+
+/// For checking IfEmpty on owned values with a borrowed fallback
+///
+/// The fallback is only converted into an owned value if it is actually used, so no allocation
+/// happens for non-empty values.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfEmptyRef;
+///
+/// assert_eq!(String::new().if_empty_ref("default"), "default");
+/// assert_eq!("value".to_string().if_empty_ref("default"), "value");
+/// ```
+pub trait IfEmptyRef<Rhs: ?Sized> {
+    /// Returns an owned copy of `val` if `self` is empty
+    fn if_empty_ref(self, val: &Rhs) -> Self;
+}
+
+/// For checking IfEmpty on borrowed values with an owned fallback
+///
+/// Returns a [`Cow`] borrowing `self` if it isn't empty and owning the fallback otherwise, so
+/// non-empty values don't need to be cloned just to unify the return type.
+///
+/// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use if_empty::IfEmptyOwned;
+///
+/// assert_eq!("".if_empty_owned(format!("user-{}", 42)), Cow::<str>::Owned("user-42".to_string()));
+/// assert_eq!("ferris".if_empty_owned(String::new()), Cow::Borrowed("ferris"));
+/// ```
+pub trait IfEmptyOwned: ToOwned {
+    /// Returns `val` if `self` is empty
+    fn if_empty_owned(&self, val: Self::Owned) -> Cow<'_, Self>;
+}
+
+macro_rules! if_empty_owned {
+    ($($(#[$generic:ident])? $type:ty),*) => {
+        $(
+            impl$(<$generic: Clone>)? IfEmptyOwned for $type {
+                /// Returns `input` if `self` is empty.
+                /// Otherwise `self` is borrowed.
+                fn if_empty_owned(&self, input: Self::Owned) -> Cow<'_, Self> {
+                    if self.is_empty() {
+                        Cow::Owned(input)
+                    } else {
+                        Cow::Borrowed(self)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+if_empty_owned!(
+    str,
+    std::ffi::OsStr,
+    #[T]
+    [T]
+);
+
+/// For checking IfEmpty on owned and borrowed values alike
+///
+/// `IfEmpty` and `IfEmptyBorrowed` split the API by how values are passed. This trait unifies
+/// both so generic code only needs a single bound: sized types like [`String`] are passed by
+/// value and unsized types like [`str`] by reference. Prefer it over the other two traits when
+/// writing code that should work for both.
+///
+/// Requires the `any` feature, as generic associated types need Rust 1.65 or newer.
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfEmptyAny;
+///
+/// fn or_default<'a, T: IfEmptyAny + ?Sized>(value: T::Value<'a>, default: T::Value<'a>) -> T::Value<'a> {
+///     T::if_empty_any(value, default)
+/// }
+///
+/// assert_eq!(or_default::<String>(String::new(), "default".to_string()), "default");
+/// assert_eq!(or_default::<str>("", "default"), "default");
+/// ```
+#[cfg(feature = "any")]
+pub trait IfEmptyAny {
+    /// How values are passed, `Self` for owned and `&'a Self` for borrowed types
+    type Value<'a>
+    where
+        Self: 'a;
+
+    /// Returns `val` if `value` is empty
+    fn if_empty_any<'a>(value: Self::Value<'a>, val: Self::Value<'a>) -> Self::Value<'a>
+    where
+        Self: 'a;
+}
+
+#[cfg(feature = "any")]
+macro_rules! if_empty_any {
+    (owned: $($(#[$generic:ident])? $type:ty),*) => {
+        $(
+            impl$(<$generic>)? IfEmptyAny for $type {
+                type Value<'a> = Self where Self: 'a;
+
+                fn if_empty_any<'a>(value: Self::Value<'a>, input: Self::Value<'a>) -> Self::Value<'a>
+                where
+                    Self: 'a,
+                {
+                    IfEmpty::if_empty(value, input)
+                }
+            }
+        )*
+    };
+    (borrowed: $($(#[$generic:ident])? $type:ty),*) => {
+        $(
+            impl$(<$generic>)? IfEmptyAny for $type {
+                type Value<'a> = &'a Self where Self: 'a;
+
+                fn if_empty_any<'a>(value: Self::Value<'a>, input: Self::Value<'a>) -> Self::Value<'a>
+                where
+                    Self: 'a,
+                {
+                    IfEmptyBorrowed::if_empty(value, input)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "any")]
+if_empty_any!(owned: String, std::ffi::OsString, #[T] Vec<T>);
+#[cfg(feature = "any")]
+if_empty_any!(borrowed: str, std::ffi::OsStr, #[T] [T]);
+
 /// Implementation of `IfEmptyBorrowed` for [`str`]
 impl IfEmptyBorrowed for str {
     /// Returns `input` if [`str::is_empty()`] returns true.
@@ -118,6 +807,19 @@ impl IfEmpty for String {
     }
 }
 
+/// Implementation of `IfEmptyRef` for [`String`] with [`str`] fallbacks
+impl IfEmptyRef<str> for String {
+    /// Returns `input` converted into a [`String`] if [`String::is_empty()`] returns true.
+    /// Otherwise `self` is returned.
+    fn if_empty_ref(self, input: &str) -> Self {
+        if self.is_empty() {
+            input.to_string()
+        } else {
+            self
+        }
+    }
+}
+
 /// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
 /// Implementation of `IfEmptyBorrowed` for [`OsStr`]
 impl IfEmptyBorrowed for std::ffi::OsStr {
@@ -148,11 +850,316 @@ impl IfEmpty for std::ffi::OsString {
     }
 }
 
+/// [`OsString`]: https://doc.rust-lang.org/std/ffi/struct.OsString.html
+/// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+/// Implementation of `IfEmptyRef` for [`OsString`] with [`OsStr`] fallbacks
+impl IfEmptyRef<std::ffi::OsStr> for std::ffi::OsString {
+    /// Returns `input` converted into an `OsString` if `self` is empty.
+    /// Otherwise `self` is returned.
+    fn if_empty_ref(self, input: &std::ffi::OsStr) -> Self {
+        if self.is_empty() {
+            input.to_os_string()
+        } else {
+            self
+        }
+    }
+}
+
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// Implementation of `IfEmpty` for [`Vec`]
+impl<T> IfEmpty for Vec<T> {
+    /// [`Vec::is_empty()`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.is_empty
+    /// Returns `input` if [`Vec::is_empty()`] returns true.
+    /// Otherwise `self` is returned.
+    fn if_empty(self, input: Self) -> Self {
+        if self.is_empty() {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`slice`]: https://doc.rust-lang.org/std/primitive.slice.html
+/// Implementation of `IfEmptyRef` for [`Vec`] with [`slice`] fallbacks
+impl<T: Clone> IfEmptyRef<[T]> for Vec<T> {
+    /// [`Vec::is_empty()`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.is_empty
+    /// Returns `input` converted into a [`Vec`] if [`Vec::is_empty()`] returns true.
+    /// Otherwise `self` is returned.
+    fn if_empty_ref(self, input: &[T]) -> Self {
+        if self.is_empty() {
+            input.to_vec()
+        } else {
+            self
+        }
+    }
+}
+
+/// [`slice`]: https://doc.rust-lang.org/std/primitive.slice.html
+/// Implementation of `IfEmptyBorrowed` for [`slice`]
+impl<T> IfEmptyBorrowed for [T] {
+    /// [`slice::is_empty()`]: https://doc.rust-lang.org/std/primitive.slice.html#method.is_empty
+    /// Returns `input` if [`slice::is_empty()`] returns true.
+    /// Otherwise `self` is returned.
+    fn if_empty<'a>(&'a self, input: &'a Self) -> &'a Self {
+        if self.is_empty() {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+/// Implementation of `IsEmpty` for [`str`]
+impl IsEmpty for str {
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+/// Implementation of `IsEmpty` for [`String`]
+impl IsEmpty for String {
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+}
+
+/// [`OsStr`]: https://doc.rust-lang.org/std/ffi/struct.OsStr.html
+/// Implementation of `IsEmpty` for [`OsStr`]
+impl IsEmpty for std::ffi::OsStr {
+    fn is_empty(&self) -> bool {
+        std::ffi::OsStr::is_empty(self)
+    }
+}
+
+/// [`OsString`]: https://doc.rust-lang.org/std/ffi/struct.OsString.html
+/// Implementation of `IsEmpty` for [`OsString`]
+impl IsEmpty for std::ffi::OsString {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// Implementation of `IsEmpty` for [`Vec`]
+impl<T> IsEmpty for Vec<T> {
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+}
+
+/// [`slice`]: https://doc.rust-lang.org/std/primitive.slice.html
+/// Implementation of `IsEmpty` for [`slice`]
+impl<T> IsEmpty for [T] {
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+/// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+/// Implementation of `IsEmpty` for [`Cow`] of types implementing it
+impl<B: IsEmpty + ToOwned + ?Sized> IsEmpty for Cow<'_, B> {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implementation of `IsEmpty` for references to types implementing it
+impl<T: IsEmpty + ?Sized> IsEmpty for &T {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implementation of `IsEmpty` for mutable references to types implementing it
+impl<T: IsEmpty + ?Sized> IsEmpty for &mut T {
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+}
+
+/// Implementation of `IfEmpty` for mutable references to types implementing `IsEmpty`
+///
+/// Mutable references get `IfEmpty` rather than `IfEmptyBorrowed`, as the latter would hand out a
+/// `&&mut T` that can't be used for mutation. Taking the references by value returns the chosen
+/// one as `&mut T`, which can be modified and reborrowed as usual.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfEmpty;
+///
+/// let mut value = Vec::new();
+/// let mut fallback = vec![1];
+/// (&mut value).if_empty(&mut fallback).push(2);
+/// assert_eq!(fallback, [1, 2]);
+/// ```
+impl<T: IsEmpty + ?Sized> IfEmpty for &mut T {
+    /// Returns `input` if the referenced value is empty.
+    /// Otherwise `self` is returned.
+    fn if_empty(self, input: Self) -> Self {
+        if (*self).is_empty() {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+/// Implementation of `IsEmpty` for arrays, which are empty if all their elements are
+///
+/// In contrast to slices, arrays always have the same length, so they are checked like fixed-size buffers of optional
+/// slots.
+impl<T: IsEmpty, const N: usize> IsEmpty for [T; N] {
+    fn is_empty(&self) -> bool {
+        self.iter().all(IsEmpty::is_empty)
+    }
+}
+
+/// Implementation of `IfEmpty` for arrays, which are empty if all their elements are
+impl<T: IsEmpty, const N: usize> IfEmpty for [T; N] {
+    /// Returns `input` if all elements of `self` are empty.
+    /// Otherwise `self` is returned.
+    fn if_empty(self, input: Self) -> Self {
+        if IsEmpty::is_empty(&self) {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! tuple_if_empty {
+    ($($name:ident $index:tt),+) => {
+        /// Implementation of `IsEmpty` for tuples, which are empty if all their elements are
+        impl<$($name: IsEmpty),+> IsEmpty for ($($name,)+) {
+            fn is_empty(&self) -> bool {
+                $(self.$index.is_empty())&&+
+            }
+        }
+
+        /// Implementation of `IfEmpty` for tuples, which are empty if all their elements are
+        impl<$($name: IsEmpty),+> IfEmpty for ($($name,)+) {
+            /// Returns `input` if all elements of `self` are empty.
+            /// Otherwise `self` is returned.
+            fn if_empty(self, input: Self) -> Self {
+                if IsEmpty::is_empty(&self) {
+                    input
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+tuple_if_empty!(A 0);
+tuple_if_empty!(A 0, B 1);
+tuple_if_empty!(A 0, B 1, C 2);
+tuple_if_empty!(A 0, B 1, C 2, D 3);
+tuple_if_empty!(A 0, B 1, C 2, D 3, E 4);
+tuple_if_empty!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_if_empty!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_if_empty!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Implementation of `IfEmpty` for [`&Path`]
+///
+/// [`&Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use if_empty::IfEmpty;
+///
+/// let config = Path::new("");
+/// assert_eq!(config.if_empty(Path::new("/etc/app.toml")), Path::new("/etc/app.toml"));
+/// ```
+impl IfEmpty for &std::path::Path {
+    /// Returns `input` if `self` is an empty path.
+    /// Otherwise `self` is returned.
+    fn if_empty(self, input: Self) -> Self {
+        if self.as_os_str().is_empty() {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+/// Implementation of `IfEmpty` for [`PathBuf`]
+impl IfEmpty for std::path::PathBuf {
+    /// Returns `input` if `self` is an empty path.
+    /// Otherwise `self` is returned.
+    fn if_empty(self, input: Self) -> Self {
+        if self.as_os_str().is_empty() {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+/// Implementation of `IfEmptyRef` for [`PathBuf`] with [`Path`] fallbacks
+///
+/// # Examples
+///
+/// ```
+/// use std::path::{Path, PathBuf};
+///
+/// use if_empty::IfEmptyRef;
+///
+/// const DEFAULT_CONFIG: &str = "/etc/app.toml";
+///
+/// let config = PathBuf::new().if_empty_ref(Path::new(DEFAULT_CONFIG));
+/// assert_eq!(config, Path::new(DEFAULT_CONFIG));
+/// ```
+impl IfEmptyRef<std::path::Path> for std::path::PathBuf {
+    /// Returns `input` converted into a `PathBuf` if `self` is empty.
+    /// Otherwise `self` is returned.
+    fn if_empty_ref(self, input: &std::path::Path) -> Self {
+        if self.as_os_str().is_empty() {
+            input.to_path_buf()
+        } else {
+            self
+        }
+    }
+}
+
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+/// Implementation of `IsEmpty` for [`Path`]
+impl IsEmpty for std::path::Path {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+/// Implementation of `IsEmpty` for [`PathBuf`]
+impl IsEmpty for std::path::PathBuf {
+    fn is_empty(&self) -> bool {
+        self.as_os_str().is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::ffi::{OsStr, OsString};
+    use std::{
+        borrow::Cow,
+        ffi::{OsStr, OsString},
+        path::{Path, PathBuf},
+        rc::Rc,
+        sync::Arc,
+    };
 
-    use crate::{IfEmpty, IfEmptyBorrowed};
+    #[cfg(feature = "any")]
+    use crate::IfEmptyAny;
+    use crate::{EmptyError, IfEmpty, IfEmptyBorrowed, IfEmptyOwned, IfEmptyRef, IsEmpty, Replaced};
 
     #[test]
     fn string() {
@@ -168,6 +1175,18 @@ mod tests {
         assert_eq!("not empty", string.if_empty("should not be returned".to_string()));
     }
     #[test]
+    fn string_into() {
+        assert_eq!(String::new().if_empty_into("text"), "text");
+        assert_eq!("not empty".to_string().if_empty_into('c'), "not empty");
+        assert_eq!(OsString::new().if_empty_into("text"), OsString::from("text"));
+        assert_eq!(Vec::<u8>::new().if_empty_into(&[1, 2][..]), vec![1, 2]);
+    }
+    #[test]
+    fn string_ref() {
+        assert_eq!(String::new().if_empty_ref("text"), "text");
+        assert_eq!("not empty".to_string().if_empty_ref("should not be returned"), "not empty");
+    }
+    #[test]
     fn str() {
         let string: &str = "";
         assert!(string.is_empty());
@@ -181,6 +1200,52 @@ mod tests {
         assert_eq!("not empty", string.if_empty("should not be returned"));
     }
     #[test]
+    fn str_owned() {
+        let replaced = "".if_empty_owned("text".to_string());
+        assert!(matches!(replaced, Cow::Owned(_)));
+        assert_eq!(replaced, "text");
+
+        let kept = "not empty".if_empty_owned("should not be returned".to_string());
+        assert!(matches!(kept, Cow::Borrowed("not empty")));
+    }
+    #[test]
+    fn os_str_owned() {
+        assert_eq!(OsStr::new("").if_empty_owned(OsString::from("text")), OsStr::new("text"));
+        assert_eq!([1].if_empty_owned(vec![2]), Cow::Borrowed(&[1][..]));
+    }
+    #[test]
+    fn cow() {
+        assert!(IsEmpty::is_empty(&Cow::Borrowed("")));
+        assert!(!IsEmpty::is_empty(&Cow::<str>::Owned("value".to_string())));
+        assert!(IsEmpty::is_empty(&Cow::<[u8]>::Owned(Vec::new())));
+    }
+    #[test]
+    fn path() {
+        let fallback = Path::new("fallback");
+        assert_eq!(Path::new("").if_empty(fallback), fallback);
+        assert_eq!(Path::new(".").if_empty(fallback), Path::new("."));
+    }
+    #[test]
+    fn os_string_ref() {
+        assert_eq!(OsString::new().if_empty_ref(OsStr::new("text")), OsString::from("text"));
+        assert_eq!(
+            OsString::from("value").if_empty_ref(OsStr::new("text")),
+            OsString::from("value")
+        );
+    }
+    #[test]
+    fn path_buf() {
+        assert_eq!(PathBuf::new().if_empty(PathBuf::from("fallback")), PathBuf::from("fallback"));
+        assert_eq!(
+            PathBuf::from("value").if_empty(PathBuf::from("fallback")),
+            PathBuf::from("value")
+        );
+        assert_eq!(PathBuf::new().if_empty_ref(Path::new("fallback")), Path::new("fallback"));
+        assert_eq!(PathBuf::from("value").if_empty_ref(Path::new("fallback")), Path::new("value"));
+        assert!(IsEmpty::is_empty(Path::new("")));
+        assert!(!IsEmpty::is_empty(&PathBuf::from(".")));
+    }
+    #[test]
     fn os_string() {
         let string = OsString::default();
         assert!(string.is_empty());
@@ -209,6 +1274,242 @@ mod tests {
         assert!(!string.is_empty());
     }
     #[test]
+    fn static_fallback() {
+        const FALLBACK: &str = "fallback";
+
+        fn short_lived(value: &str) -> &str {
+            value.if_empty(FALLBACK)
+        }
+
+        let owned = String::new();
+        assert_eq!(short_lived(&owned), FALLBACK);
+        assert_eq!(short_lived(String::from("value").as_str()), "value");
+        let path: &'static Path = Path::new("/tmp");
+        assert_eq!(PathBuf::new().as_path().if_empty(path), path);
+    }
+    #[test]
+    fn vec() {
+        let vec: Vec<u8> = Vec::new();
+        assert_eq!(vec.if_empty(vec![1, 2]), vec![1, 2]);
+        assert_eq!(vec![3].if_empty(vec![1, 2]), vec![3]);
+    }
+    #[test]
+    fn vec_ref() {
+        assert_eq!(Vec::new().if_empty_ref(&[1, 2]), vec![1, 2]);
+        assert_eq!(vec![3].if_empty_ref(&[1, 2]), vec![3]);
+    }
+    #[test]
+    fn slice() {
+        let slice: &[u8] = &[];
+        assert_eq!(slice.if_empty(&[1, 2]), &[1, 2]);
+        assert_eq!([3].if_empty(&[1, 2]), &[3]);
+    }
+    #[cfg(feature = "any")]
+    #[test]
+    fn if_empty_any() {
+        fn replaced<'a, T: IfEmptyAny + ?Sized>(value: T::Value<'a>, fallback: T::Value<'a>) -> T::Value<'a> {
+            T::if_empty_any(value, fallback)
+        }
+
+        assert_eq!(replaced::<String>(String::new(), "text".to_string()), "text");
+        assert_eq!(
+            replaced::<OsString>(OsString::from("value"), OsString::new()),
+            OsString::from("value")
+        );
+        assert_eq!(replaced::<Vec<u8>>(Vec::new(), vec![1]), vec![1]);
+        assert_eq!(replaced::<str>("", "text"), "text");
+        assert_eq!(replaced::<OsStr>(OsStr::new(""), OsStr::new("text")), OsStr::new("text"));
+        assert_eq!(replaced::<[u8]>(&[2], &[1]), &[2]);
+    }
+    #[test]
+    fn is_empty() {
+        fn check<T: IsEmpty + ?Sized>(value: &T) -> bool {
+            value.is_empty()
+        }
+
+        assert!(check(""));
+        assert!(!check("text"));
+        assert!(check(&String::new()));
+        assert!(!check(&"text".to_string()));
+        assert!(check(OsStr::new("")));
+        assert!(!check(&OsString::from("text")));
+        assert!(check(&Vec::<u8>::new()));
+        assert!(!check(&[1][..]));
+        assert!(check(&&""));
+        assert!(check(&&mut String::new()));
+    }
+    #[test]
+    fn tuple() {
+        assert!(("", Vec::<u8>::new()).is_empty());
+        assert!(!("", vec![1]).is_empty());
+        assert_eq!(
+            (String::new(), "").if_empty(("host".to_string(), "user")),
+            ("host".to_string(), "user")
+        );
+        assert_eq!(("", "user").if_empty(("host", "fallback")), ("", "user"));
+        assert!(("", "", "", "", "", "", "", "").is_empty());
+    }
+    #[test]
+    fn array() {
+        assert!(IsEmpty::is_empty(&["", ""]));
+        assert!(!IsEmpty::is_empty(&["", "slot"]));
+        assert!(IsEmpty::is_empty(&[""; 0]));
+        assert_eq!(["", ""].if_empty(["a", "b"]), ["a", "b"]);
+        assert_eq!([vec![], vec![1]].if_empty([vec![2], vec![3]]), [vec![], vec![1]]);
+    }
+    #[test]
+    fn deref() {
+        let boxed: Box<str> = Box::from("");
+        assert_eq!(boxed.if_empty("fallback"), "fallback");
+        assert_eq!(Rc::<str>::from("").if_empty("fallback"), "fallback");
+        assert_eq!(Rc::<str>::from("value").if_empty("fallback"), "value");
+        let slice: Box<[u8]> = Box::new([]);
+        assert_eq!(slice.if_empty(&[1]), [1]);
+        assert_eq!(Rc::<OsStr>::from(OsStr::new("")).if_empty(OsStr::new("os")), "os");
+
+        {
+            use crate::IfEmptyDeref;
+
+            assert_eq!(Box::new(vec![1]).if_empty(&vec![2]), &vec![1]);
+            assert_eq!(Arc::new(String::new()).if_empty(&"value".to_string()), "value");
+            assert_eq!(Rc::<str>::from("").if_empty("fallback"), "fallback");
+        }
+    }
+    #[test]
+    fn mut_ref() {
+        let mut value = String::new();
+        let mut fallback = "fallback".to_string();
+        value.as_mut_str().if_empty(fallback.as_mut_str()).make_ascii_uppercase();
+        assert_eq!(value, "");
+        assert_eq!(fallback, "FALLBACK");
+
+        let mut value = vec![1];
+        let mut fallback = vec![2];
+        (&mut value).if_empty(&mut fallback).push(3);
+        assert_eq!(value, [1, 3]);
+        assert_eq!(fallback, [2]);
+    }
+    #[test]
+    fn if_empty_with() {
+        assert_eq!(String::new().if_empty_with(|| "text".to_string()), "text");
+        assert_eq!("not empty".if_empty_with(|| unreachable!()), "not empty");
+    }
+    #[test]
+    fn if_empty_with_context() {
+        assert_eq!(
+            String::new().if_empty_with_context("ctx", |ctx| format!("{}-default", ctx)),
+            "ctx-default"
+        );
+        assert_eq!(vec![1].if_empty_with_context(&[2][..], |_| unreachable!()), vec![1]);
+    }
+    #[test]
+    fn try_if_empty_with() {
+        assert_eq!(
+            String::new().try_if_empty_with(|| Ok::<_, ()>("text".to_string())),
+            Ok("text".to_string())
+        );
+        assert_eq!(String::new().try_if_empty_with(|| Err("error")), Err("error"));
+        assert_eq!(vec![1].try_if_empty_with(|| -> Result<_, ()> { unreachable!() }), Ok(vec![1]));
+    }
+    #[test]
+    fn if_empty_checked() {
+        assert_eq!(String::new().if_empty_checked("text".to_string()), Ok("text".to_string()));
+        assert_eq!(vec![1].if_empty_checked(Vec::new()), Ok(vec![1]));
+        assert_eq!("".if_empty_checked(""), Err(EmptyError::new::<&str>()));
+    }
+    #[test]
+    fn if_empty_reporting() {
+        assert_eq!(
+            String::new().if_empty_reporting("text".to_string()),
+            ("text".to_string(), Replaced::Yes)
+        );
+        assert_eq!(vec![1].if_empty_reporting(vec![2]), (vec![1], Replaced::No));
+    }
+    #[test]
+    fn map_if_not_empty() {
+        assert_eq!(" Text ".to_string().map_if_not_empty(|s| s.trim().to_lowercase()), "text");
+        assert_eq!(String::new().map_if_not_empty(|_| unreachable!()), "");
+        assert_eq!(
+            vec![1].map_if_not_empty(|mut v| {
+                v.push(2);
+                v
+            }),
+            [1, 2]
+        );
+    }
+    #[test]
+    fn inspect_if_empty() {
+        let mut calls = 0;
+        assert_eq!(String::new().inspect_if_empty(|| calls += 1), "");
+        assert_eq!("not empty".inspect_if_empty(|| calls += 1), "not empty");
+        assert_eq!(calls, 1);
+    }
+    #[test]
+    fn free_functions() {
+        assert_eq!(crate::if_empty(String::new(), "text".to_string()), "text");
+        assert_eq!(crate::if_empty(vec![1], vec![2]), vec![1]);
+        assert_eq!(crate::if_empty_with("", || "text"), "text");
+        assert_eq!(
+            crate::if_empty_with(OsString::from("value"), || unreachable!()),
+            OsString::from("value")
+        );
+    }
+    #[test]
+    fn if_empty_const() {
+        const EMPTY: &str = crate::if_empty_const("", "text");
+        const NOT_EMPTY: &str = crate::if_empty_const("not empty", "should not be returned");
+        assert_eq!(EMPTY, "text");
+        assert_eq!(NOT_EMPTY, "not empty");
+    }
+    #[test]
+    fn first_non_empty() {
+        assert_eq!(crate::first_non_empty(vec!["", "second", "third"]), Some("second"));
+        assert_eq!(crate::first_non_empty(Vec::<String>::new()), None);
+        assert_eq!(crate::first_non_empty_or(vec![Vec::new(), Vec::new()], vec![1]), vec![1]);
+
+        let mut candidates = vec!["", "second", "third"].into_iter();
+        crate::first_non_empty(&mut candidates);
+        assert_eq!(candidates.next(), Some("third"));
+    }
+    #[test]
+    fn empty_to_none() {
+        assert_eq!(String::new().empty_to_none(), None);
+        assert_eq!("text".to_string().empty_to_none(), Some("text".to_string()));
+        assert_eq!(IsEmpty::empty_to_none(""), None);
+        assert_eq!(Some(vec![1]).and_then(IsEmpty::empty_to_none), Some(vec![1]));
+    }
+    #[test]
+    fn ok_or_empty() {
+        fn name(value: &str) -> Result<&str, String> {
+            Ok(value.ok_or_empty("name is required")?)
+        }
+
+        assert_eq!(name(""), Err("name is required".to_string()));
+        assert_eq!(name("ferris"), Ok("ferris"));
+        assert_eq!(Vec::<u8>::new().ok_or_empty_else(|| 42), Err(42));
+    }
+    #[test]
+    fn expect_not_empty() {
+        assert_eq!("text".expect_not_empty("text is required"), "text");
+        assert_eq!(vec![1].unwrap_not_empty(), vec![1]);
+    }
+    #[test]
+    #[should_panic(expected = "name is required: value of type `")]
+    fn expect_not_empty_panics() {
+        String::new().expect_not_empty("name is required");
+    }
+    #[test]
+    #[should_panic(expected = "called `unwrap_not_empty()` on an empty value")]
+    fn unwrap_not_empty_panics() {
+        OsString::new().unwrap_not_empty();
+    }
+    #[test]
+    fn as_non_empty() {
+        assert_eq!("".as_non_empty(), None);
+        assert_eq!("text".as_non_empty(), Some("text"));
+        assert_eq!(OsStr::new("text").as_non_empty().map(OsStr::len), Some(4));
+    }
+    #[test]
     fn custom() {
         struct Fake {
             value: bool,
@@ -245,6 +1546,366 @@ mod tests {
         );
     }
 
+    #[test]
+    fn derive_macro_len() {
+        #[derive(IfEmpty)]
+        #[if_empty(len)]
+        struct Queue {
+            items: Vec<u32>,
+        }
+
+        impl Queue {
+            fn len(&self) -> usize {
+                self.items.len()
+            }
+        }
+
+        let queue = Queue {
+            items: Vec::new(),
+        };
+        assert!(queue.is_empty());
+        assert_eq!(
+            queue
+                .if_empty(Queue {
+                    items: vec![1],
+                })
+                .items,
+            vec![1]
+        );
+    }
+    #[test]
+    fn derive_macro_delegate() {
+        #[derive(IfEmpty)]
+        #[if_empty(delegate)]
+        struct UserId(String);
+
+        #[derive(IfEmpty)]
+        #[if_empty(delegate)]
+        struct Tags {
+            tags: Vec<String>,
+        }
+
+        assert!(UserId(String::new()).is_empty());
+        assert_eq!(UserId(String::new()).if_empty(UserId("guest".to_string())).0, "guest");
+        assert_eq!(UserId("id".to_string()).if_empty(UserId("guest".to_string())).0, "id");
+        assert!(!Tags {
+            tags: vec!["tag".to_string()]
+        }
+        .is_empty());
+    }
+    #[test]
+    fn derive_macro_fields() {
+        #[derive(IfEmpty)]
+        #[if_empty(all)]
+        struct Contact {
+            email: String,
+            phones: Vec<String>,
+        }
+
+        #[derive(IfEmpty)]
+        #[if_empty(any)]
+        struct Credentials(String, String);
+
+        let contact = |email: &str, phones: &[&str]| Contact {
+            email: email.to_string(),
+            phones: phones.iter().map(|phone| phone.to_string()).collect(),
+        };
+        assert!(contact("", &[]).is_empty());
+        assert!(!contact("", &["123"]).is_empty());
+        assert_eq!(contact("", &[]).if_empty(contact("mail", &[])).email, "mail");
+
+        assert!(Credentials("user".to_string(), String::new()).is_empty());
+        assert!(!Credentials("user".to_string(), "password".to_string()).is_empty());
+    }
+    #[test]
+    fn derive_macro_traits() {
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(owned, borrowed)]
+        struct Name {
+            value: String,
+        }
+
+        impl Name {
+            fn is_empty(&self) -> bool {
+                self.value.is_empty()
+            }
+        }
+
+        fn owned<T: IfEmpty>(value: T, fallback: T) -> T {
+            value.if_empty(fallback)
+        }
+
+        let name = |value: &str| Name {
+            value: value.to_string(),
+        };
+        assert_eq!(owned(name(""), name("fallback")), name("fallback"));
+        assert_eq!(IfEmptyBorrowed::if_empty(&name(""), &name("fallback")), &name("fallback"));
+        assert_eq!(IfEmptyBorrowed::if_empty(&name("value"), &name("fallback")), &name("value"));
+    }
+    #[test]
+    fn derive_macro_defaults() {
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(derive_default)]
+        struct Server {
+            #[if_empty(default = "localhost")]
+            host: String,
+            #[if_empty(default = vec![80, 443])]
+            ports: Vec<u16>,
+            aliases: Vec<String>,
+        }
+
+        impl Server {
+            fn is_empty(&self) -> bool {
+                self.host.is_empty()
+            }
+        }
+
+        let defaults = Server::default();
+        assert!(!defaults.is_empty());
+        assert_eq!(defaults.host, "localhost");
+        assert_eq!(defaults.ports, [80, 443]);
+        assert!(defaults.aliases.is_empty());
+
+        let server = Server {
+            host: String::new(),
+            ports: vec![8080],
+            aliases: Vec::new(),
+        }
+        .fill_empty_defaults();
+        assert_eq!(
+            server,
+            Server {
+                host: "localhost".to_string(),
+                ports: vec![8080],
+                aliases: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn derive_macro_defaults_scope() {
+        mod scope {
+            // Only the derive and `IfEmpty` are in scope, `IsEmpty` has to be used by the generated code itself
+            use crate::IfEmpty;
+
+            fn fill(_: &Columns) -> [String; 1] {
+                ["filled".to_string()]
+            }
+
+            #[derive(IfEmpty)]
+            #[if_empty(all)]
+            pub struct Columns {
+                #[if_empty(with = fill)]
+                pub names: [String; 1],
+                #[if_empty(default = ("a", "b"))]
+                pub pair: (&'static str, &'static str),
+            }
+
+            pub fn filled(columns: Columns) -> Columns {
+                columns.fill_empty_defaults()
+            }
+        }
+
+        let columns = scope::filled(scope::Columns {
+            names: [String::new()],
+            pair: ("", ""),
+        });
+        assert_eq!(columns.names, ["filled"]);
+        assert_eq!(columns.pair, ("a", "b"));
+    }
+
+    #[test]
+    fn derive_macro_delegate_scope() {
+        mod scope {
+            // `IsEmpty` is not in scope, so `is_empty` on the array would resolve to the slice length
+            use crate::IfEmpty;
+
+            #[derive(IfEmpty, Debug, PartialEq)]
+            #[if_empty(delegate)]
+            pub struct Row(pub [String; 2]);
+
+            pub fn if_empty(row: Row, fallback: Row) -> Row {
+                row.if_empty(fallback)
+            }
+        }
+
+        let row = |a: &str, b: &str| scope::Row([a.to_string(), b.to_string()]);
+        assert_eq!(scope::if_empty(row("", ""), row("a", "b")), row("a", "b"));
+        assert_eq!(scope::if_empty(row("", "b"), row("a", "b")), row("", "b"));
+    }
+
+    #[test]
+    fn derive_macro_with() {
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(all, with = |_: &Draft| Draft { title: "closure".to_string() })]
+        struct Draft {
+            title: String,
+        }
+
+        assert_eq!(
+            Draft {
+                title: String::new()
+            }
+            .fill_empty_defaults()
+            .title,
+            "closure"
+        );
+
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(all, with = "Account::guest")]
+        struct Account {
+            username: String,
+            #[if_empty(with = default_email)]
+            email: String,
+        }
+
+        impl Account {
+            fn guest(_: &Self) -> Self {
+                Self {
+                    username: "guest".to_string(),
+                    email: String::new(),
+                }
+            }
+        }
+
+        fn default_email(account: &Account) -> String {
+            format!("{}@example.com", account.username)
+        }
+
+        let account = Account {
+            username: "ferris".to_string(),
+            email: String::new(),
+        }
+        .fill_empty_defaults();
+        assert_eq!(account.email, "ferris@example.com");
+
+        let account = Account {
+            username: String::new(),
+            email: String::new(),
+        };
+        assert!(account.is_empty());
+        assert_eq!(
+            account.fill_empty_defaults(),
+            Account {
+                username: "guest".to_string(),
+                email: "guest@example.com".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn derive_macro_trait() {
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(trait)]
+        struct Tags(Vec<String>);
+
+        impl IsEmpty for Tags {
+            fn is_empty(&self) -> bool {
+                self.0.iter().all(String::is_empty)
+            }
+        }
+
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(all, trait, owned)]
+        struct Profile {
+            name: String,
+            bio: String,
+        }
+
+        let fallback = || Tags(vec!["untagged".to_string()]);
+        assert_eq!(Tags(vec![String::new()]).if_empty(fallback()), fallback());
+
+        let profile = |name: &str| Profile {
+            name: name.to_string(),
+            bio: String::new(),
+        };
+        assert!(IsEmpty::is_empty(&profile("")));
+        assert_eq!(IfEmpty::if_empty(profile(""), profile("ferris")), profile("ferris"));
+    }
+
+    #[test]
+    fn derive_macro_generics() {
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(all, derive_default)]
+        struct Page<T> {
+            items: Vec<T>,
+            title: T,
+        }
+
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(delegate, owned)]
+        struct Queue<T>(Vec<T>);
+
+        #[derive(Debug, PartialEq)]
+        struct Job;
+
+        let page = |items: Vec<&str>, title: &str| Page {
+            items: items.into_iter().map(String::from).collect(),
+            title: title.to_string(),
+        };
+        assert!(Page::<String>::default().is_empty());
+        assert_eq!(page(vec![], "").if_empty(page(vec!["a"], "b")), page(vec!["a"], "b"));
+        assert_eq!(page(vec![], "title").if_empty(page(vec!["a"], "b")), page(vec![], "title"));
+
+        assert_eq!(Queue(Vec::new()).if_empty(Queue(vec![Job])), Queue(vec![Job]));
+    }
+
+    #[test]
+    fn derive_macro_const_generics() {
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(all, derive_default)]
+        struct Buf<const N: usize> {
+            data: [String; N],
+        }
+
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(delegate, owned)]
+        struct Row<T, const N: usize>([T; N]);
+
+        let buf = |value: &str| Buf {
+            data: [value.to_string(), String::new()],
+        };
+        assert!(Buf::<4>::default().is_empty());
+        assert_eq!(buf("").if_empty(buf("fallback")), buf("fallback"));
+        assert_eq!(Row([""; 2]).if_empty(Row(["", "b"])), Row(["", "b"]));
+    }
+
+    #[test]
+    fn derive_macro_attributes() {
+        #[derive(IfEmpty)]
+        #[if_empty(delegate, inline = false, must_use = false)]
+        struct Unused(String);
+
+        #[derive(IfEmpty, Debug, PartialEq)]
+        #[if_empty(delegate, inline, must_use = true, doc = "A `Used` is empty if its name is.")]
+        struct Used(String);
+
+        Unused(String::new()).if_empty(Unused("fallback".to_string()));
+        assert_eq!(
+            Used(String::new()).if_empty(Used("fallback".to_string())),
+            Used("fallback".to_string())
+        );
+    }
+
+    #[test]
+    fn derive_macro_crate() {
+        mod facade {
+            pub use crate as reexported;
+        }
+
+        #[derive(IfEmpty, crate::MergeEmpty, Debug, PartialEq)]
+        #[if_empty(all, owned, crate = "facade::reexported")]
+        struct Label {
+            text: String,
+        }
+
+        let label = |text: &str| Label {
+            text: text.to_string(),
+        };
+        assert_eq!(label("").if_empty(label("fallback")), label("fallback"));
+        assert_eq!(crate::MergeEmpty::merge_from(label("value"), label("other")), label("value"));
+    }
+
     #[test]
     fn derive_macro() {
         #[derive(IfEmpty)]
@@ -0,0 +1,253 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Returns `fallback` if `value` is empty
+///
+/// Works with any expression whose type has an `is_empty()` method, so no trait implementation is
+/// required. `fallback` is only evaluated if `value` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use if_empty::if_empty;
+///
+/// let headers: HashMap<&str, &str> = HashMap::new();
+/// let headers = if_empty!(headers, vec![("accept", "*/*")].into_iter().collect());
+/// assert_eq!(headers["accept"], "*/*");
+///
+/// assert_eq!(if_empty!("text", "should not be returned"), "text");
+/// ```
+#[macro_export]
+macro_rules! if_empty {
+    ($value:expr, $fallback:expr $(,)?) => {
+        match $value {
+            value => {
+                if value.is_empty() {
+                    $fallback
+                } else {
+                    value
+                }
+            }
+        }
+    };
+}
+
+/// Returns the first non-empty value
+///
+/// Candidates are evaluated left to right and only until a non-empty one is found. The last
+/// candidate is returned as is, even if it is empty, so it usually is the default for your
+/// context.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::coalesce;
+///
+/// let cli = "";
+/// let env = "";
+/// let file = "from file";
+/// assert_eq!(coalesce!(cli, env, file, "default"), "from file");
+/// assert_eq!(coalesce!(cli, env, "default"), "default");
+/// ```
+#[macro_export]
+macro_rules! coalesce {
+    ($value:expr $(,)?) => {
+        $value
+    };
+    ($value:expr, $($rest:expr),+ $(,)?) => {
+        $crate::if_empty!($value, $crate::coalesce!($($rest),+))
+    };
+}
+
+/// Asserts that `value` is not empty
+///
+/// Works with any expression whose type has an `is_empty()` method. The panic message includes
+/// the expression and its type. An additional message can be provided like for [`assert!`].
+///
+/// [`assert!`]: https://doc.rust-lang.org/std/macro.assert.html
+///
+/// # Examples
+///
+/// ```should_panic
+/// use if_empty::assert_not_empty;
+///
+/// let token = String::new();
+/// assert_not_empty!(token, "a token is required to talk to {}", "the API");
+/// ```
+#[macro_export]
+macro_rules! assert_not_empty {
+    ($value:expr $(,)?) => {
+        match &$value {
+            value => {
+                if value.is_empty() {
+                    ::std::panic!(
+                        "assertion failed: `{}` of type `{}` is empty",
+                        stringify!($value),
+                        $crate::__private::type_name_of(value)
+                    );
+                }
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                if value.is_empty() {
+                    ::std::panic!(
+                        "assertion failed: `{}` of type `{}` is empty: {}",
+                        stringify!($value),
+                        $crate::__private::type_name_of(value),
+                        format_args!($($arg)+)
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that `value` is not empty in debug builds
+///
+/// Like [`assert_not_empty!`] but only enabled if `debug_assertions` are, analogous to
+/// [`debug_assert!`].
+///
+/// [`debug_assert!`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+#[macro_export]
+macro_rules! debug_assert_not_empty {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_empty!($($arg)*);
+        }
+    };
+}
+
+/// Formats `value` or `placeholder` if `value` is empty
+///
+/// Shorthand for [`format!`] with a [`display_or`] argument, so `value` has to implement `IsEmpty` and
+/// [`Display`].
+///
+/// [`format!`]: https://doc.rust-lang.org/std/macro.format.html
+/// [`display_or`]: crate::display_or
+/// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::format_or;
+///
+/// let user = "";
+/// assert_eq!(format_or!("user: {}", user; "N/A"), "user: N/A");
+/// assert_eq!(format_or!("[{:^5}]", "ab"; "-"), "[ ab  ]");
+/// ```
+#[macro_export]
+macro_rules! format_or {
+    ($fmt:literal, $value:expr; $placeholder:expr $(,)?) => {
+        ::std::format!($fmt, $crate::display_or($value, $placeholder))
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub fn type_name_of<T: ?Sized>(_: &T) -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn if_empty() {
+        assert_eq!(if_empty!(String::new(), "text".to_string()), "text");
+        assert_eq!(if_empty!("value", "text"), "value");
+
+        let set: BTreeSet<u8> = BTreeSet::new();
+        assert_eq!(if_empty!(set, vec![1].into_iter().collect()).len(), 1);
+    }
+
+    #[test]
+    fn if_empty_lazy() {
+        let mut evaluated = false;
+        let value = if_empty!("value", {
+            evaluated = true;
+            "text"
+        });
+        assert_eq!(value, "value");
+        assert!(!evaluated);
+    }
+
+    #[test]
+    fn coalesce() {
+        assert_eq!(coalesce!("default"), "default");
+        assert_eq!(coalesce!("", "", "third", "default"), "third");
+        assert_eq!(coalesce!(String::new(), String::new(), "default".to_string(),), "default");
+        assert_eq!(coalesce!(vec![1], Vec::new()), vec![1]);
+    }
+
+    #[test]
+    fn coalesce_lazy() {
+        let mut evaluated = 0;
+        let mut candidate = |value| {
+            evaluated += 1;
+            value
+        };
+        assert_eq!(coalesce!(candidate(""), candidate("second"), candidate("third")), "second");
+        assert_eq!(evaluated, 2);
+    }
+
+    #[test]
+    fn assert_not_empty() {
+        assert_not_empty!("text");
+        assert_not_empty!(vec![1], "with {}", "message");
+        debug_assert_not_empty!(String::from("text"));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `name` of type `&str` is empty")]
+    fn assert_not_empty_panics() {
+        let name = "";
+        assert_not_empty!(name);
+    }
+
+    #[test]
+    #[should_panic(expected = "is empty: name is required")]
+    fn assert_not_empty_message() {
+        assert_not_empty!(String::new(), "{} is required", "name");
+    }
+
+    #[test]
+    fn format_or() {
+        assert_eq!(format_or!("{}", ""; "N/A"), "N/A");
+        assert_eq!(format_or!("name: {}", String::from("ferris"); "N/A"), "name: ferris");
+        assert_eq!(format_or!("{:>3}|", &String::new(); '-'), "  -|");
+    }
+
+    #[test]
+    fn shadowed_std_macros() {
+        #[allow(unused_macros)]
+        macro_rules! format {
+            ($($arg:tt)*) => {
+                compile_error!("the `format!` of the caller was used")
+            };
+        }
+        #[allow(unused_macros)]
+        macro_rules! panic {
+            ($($arg:tt)*) => {
+                compile_error!("the `panic!` of the caller was used")
+            };
+        }
+
+        assert_eq!(format_or!("{}", ""; "N/A"), "N/A");
+        assert_not_empty!("text");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "assertion failed: `Vec::<u8>::new()`")]
+    fn debug_assert_not_empty() {
+        debug_assert_not_empty!(Vec::<u8>::new());
+    }
+}
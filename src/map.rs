@@ -0,0 +1,184 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{
+    collections::{btree_map, hash_map, BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::IsEmpty;
+
+/// Extension methods for maps with values that can be empty
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`].
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use if_empty::MapExt;
+///
+/// let mut labels: HashMap<&str, String> = HashMap::new();
+/// labels.insert("env", String::new());
+/// labels.insert("team", "core".to_string());
+///
+/// labels.fill_empty_values_with(|key| format!("unknown-{}", key));
+/// assert_eq!(labels["env"], "unknown-env");
+/// assert_eq!(labels["team"], "core");
+/// ```
+pub trait MapExt<K, V> {
+    /// Replaces empty values with the result of `f` for their key
+    ///
+    /// `f` is only called for empty values.
+    fn fill_empty_values_with<F: FnMut(&K) -> V>(&mut self, f: F)
+    where
+        V: IsEmpty;
+
+    /// Replaces empty values with clones of `fallback`
+    fn fill_empty_values(&mut self, fallback: V)
+    where
+        V: IsEmpty + Clone,
+    {
+        self.fill_empty_values_with(|_| fallback.clone())
+    }
+
+    /// Inserts `value` if `key` is missing or its value is empty
+    ///
+    /// Returns a mutable reference to the value stored for `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use if_empty::MapExt;
+    ///
+    /// let mut headers = BTreeMap::new();
+    /// headers.insert("accept", String::new());
+    ///
+    /// headers.or_insert_if_empty("accept", "*/*".to_string());
+    /// headers.or_insert_if_empty("user-agent", "if_empty".to_string());
+    /// assert_eq!(headers["accept"], "*/*");
+    /// assert_eq!(headers["user-agent"], "if_empty");
+    /// ```
+    fn or_insert_if_empty(&mut self, key: K, value: V) -> &mut V
+    where
+        V: IsEmpty;
+}
+
+/// Implementation of `MapExt` for [`HashMap`]
+///
+/// [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+impl<K: Eq + Hash, V, S: BuildHasher> MapExt<K, V> for HashMap<K, V, S> {
+    fn fill_empty_values_with<F: FnMut(&K) -> V>(&mut self, f: F)
+    where
+        V: IsEmpty,
+    {
+        fill_empty_values_with(self.iter_mut(), f)
+    }
+
+    fn or_insert_if_empty(&mut self, key: K, value: V) -> &mut V
+    where
+        V: IsEmpty,
+    {
+        match self.entry(key) {
+            hash_map::Entry::Occupied(mut entry) => {
+                if entry.get().is_empty() {
+                    entry.insert(value);
+                }
+                entry.into_mut()
+            }
+            hash_map::Entry::Vacant(entry) => entry.insert(value),
+        }
+    }
+}
+
+/// Implementation of `MapExt` for [`BTreeMap`]
+///
+/// [`BTreeMap`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+impl<K: Ord, V> MapExt<K, V> for BTreeMap<K, V> {
+    fn fill_empty_values_with<F: FnMut(&K) -> V>(&mut self, f: F)
+    where
+        V: IsEmpty,
+    {
+        fill_empty_values_with(self.iter_mut(), f)
+    }
+
+    fn or_insert_if_empty(&mut self, key: K, value: V) -> &mut V
+    where
+        V: IsEmpty,
+    {
+        match self.entry(key) {
+            btree_map::Entry::Occupied(mut entry) => {
+                if entry.get().is_empty() {
+                    entry.insert(value);
+                }
+                entry.into_mut()
+            }
+            btree_map::Entry::Vacant(entry) => entry.insert(value),
+        }
+    }
+}
+
+fn fill_empty_values_with<'a, K: 'a, V: IsEmpty + 'a, F: FnMut(&K) -> V>(
+    entries: impl Iterator<Item = (&'a K, &'a mut V)>,
+    mut f: F,
+) {
+    for (key, value) in entries {
+        if value.is_empty() {
+            *value = f(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::MapExt;
+
+    #[test]
+    fn fill_empty_values() {
+        let mut map: BTreeMap<u8, Vec<u8>> = vec![(1, vec![]), (2, vec![2]), (3, vec![])].into_iter().collect();
+        map.fill_empty_values(vec![0]);
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            [(1, vec![0]), (2, vec![2]), (3, vec![0])]
+        );
+    }
+
+    #[test]
+    fn fill_empty_values_with() {
+        let mut map: HashMap<&str, String> = vec![("host", String::new()), ("user", "ferris".to_string())]
+            .into_iter()
+            .collect();
+        let mut calls = 0;
+        map.fill_empty_values_with(|key| {
+            calls += 1;
+            format!("default-{}", key)
+        });
+        assert_eq!(map["host"], "default-host");
+        assert_eq!(map["user"], "ferris");
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn or_insert_if_empty() {
+        let mut map = HashMap::new();
+        map.insert("empty", String::new());
+        map.insert("set", "value".to_string());
+        map.or_insert_if_empty("empty", "filled".to_string()).push('!');
+        assert_eq!(map.or_insert_if_empty("set", "ignored".to_string()), "value");
+        assert_eq!(map.or_insert_if_empty("missing", "inserted".to_string()), "inserted");
+        assert_eq!(map["empty"], "filled!");
+
+        let mut map = BTreeMap::new();
+        assert_eq!(map.or_insert_if_empty(1, vec![1]), &vec![1]);
+        assert_eq!(map.or_insert_if_empty(1, vec![2]), &vec![1]);
+    }
+}
@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{borrow::Cow, ffi::OsString, path::PathBuf};
+
+use crate::IsEmpty;
+
+/// For filling the empty parts of a value from another one
+///
+/// Layered configuration can be expressed by chaining calls from the most to the least specific
+/// source. Use `#[derive(MergeEmpty)]` to merge structs field by field.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::MergeEmpty;
+///
+/// #[derive(MergeEmpty)]
+/// struct Config {
+///     host: String,
+///     user: String,
+///     port: Option<u16>,
+/// }
+///
+/// let cli = Config {
+///     host: String::new(),
+///     user: "ferris".to_string(),
+///     port: None,
+/// };
+/// let defaults = Config {
+///     host: "localhost".to_string(),
+///     user: "root".to_string(),
+///     port: Some(8080),
+/// };
+///
+/// let config = cli.merge_from(defaults);
+/// assert_eq!(config.host, "localhost");
+/// assert_eq!(config.user, "ferris");
+/// assert_eq!(config.port, Some(8080));
+/// ```
+pub trait MergeEmpty {
+    /// Returns `self` with its empty parts filled from `other`
+    fn merge_from(self, other: Self) -> Self;
+}
+
+macro_rules! merge_empty_if_empty {
+    ($([$($generics:tt)*] $type:ty),*) => {
+        $(
+            impl<$($generics)*> MergeEmpty for $type {
+                /// Returns `other` if `self` is empty.
+                /// Otherwise `self` is returned.
+                fn merge_from(self, other: Self) -> Self {
+                    if IsEmpty::is_empty(&self) {
+                        other
+                    } else {
+                        self
+                    }
+                }
+            }
+        )*
+    };
+}
+
+merge_empty_if_empty!(
+    [] String,
+    [] OsString,
+    [] PathBuf,
+    [T] Vec<T>,
+    [B: IsEmpty + ToOwned + ?Sized] Cow<'_, B>,
+    [T: IsEmpty, const N: usize] [T; N]
+);
+
+#[cfg(feature = "numeric")]
+merge_empty_if_empty!([] std::time::Duration);
+
+macro_rules! merge_empty_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: IsEmpty),+> MergeEmpty for ($($name,)+) {
+            /// Returns `other` if all elements of `self` are empty.
+            /// Otherwise `self` is returned.
+            fn merge_from(self, other: Self) -> Self {
+                if IsEmpty::is_empty(&self) {
+                    other
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+merge_empty_tuple!(A);
+merge_empty_tuple!(A, B);
+merge_empty_tuple!(A, B, C);
+merge_empty_tuple!(A, B, C, D);
+merge_empty_tuple!(A, B, C, D, E);
+merge_empty_tuple!(A, B, C, D, E, F);
+merge_empty_tuple!(A, B, C, D, E, F, G);
+merge_empty_tuple!(A, B, C, D, E, F, G, H);
+
+/// Implementation of `MergeEmpty` for [`Option`]
+///
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+impl<T> MergeEmpty for Option<T> {
+    /// Returns `other` if `self` is `None`.
+    /// Otherwise `self` is returned.
+    fn merge_from(self, other: Self) -> Self {
+        self.or(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, ffi::OsString, path::PathBuf};
+
+    use crate::MergeEmpty;
+
+    #[test]
+    fn merge_from() {
+        assert_eq!(String::new().merge_from("other".to_string()), "other");
+        assert_eq!("value".to_string().merge_from("other".to_string()), "value");
+        assert_eq!(OsString::new().merge_from(OsString::from("other")), OsString::from("other"));
+        assert_eq!(Vec::new().merge_from(vec![1]), vec![1]);
+        assert_eq!(PathBuf::new().merge_from(PathBuf::from("other")), PathBuf::from("other"));
+        assert_eq!(Cow::Borrowed("").merge_from(Cow::Borrowed("other")), "other");
+        assert_eq!(["", ""].merge_from(["a", "b"]), ["a", "b"]);
+        assert_eq!(["", "value"].merge_from(["a", "b"]), ["", "value"]);
+        assert_eq!(
+            (String::new(), Vec::<u8>::new()).merge_from(("a".to_string(), vec![1])).1,
+            [1]
+        );
+    }
+
+    #[test]
+    fn option() {
+        assert_eq!(None.merge_from(Some("other".to_string())), Some("other".to_string()));
+        assert_eq!(Some(1).merge_from(Some(2)), Some(1));
+        assert_eq!(Some("value".to_string()).merge_from(None), Some("value".to_string()));
+        assert_eq!(None::<String>.merge_from(None), None);
+    }
+
+    #[test]
+    fn derive() {
+        #[derive(MergeEmpty, Debug, PartialEq)]
+        struct Server {
+            host: String,
+            aliases: Vec<String>,
+        }
+
+        #[derive(MergeEmpty, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            server: Server,
+        }
+
+        #[derive(MergeEmpty, Debug, PartialEq)]
+        struct Tuple(String, Option<String>);
+
+        let config = |name: &str, host: &str, aliases: &[&str]| Config {
+            name: name.to_string(),
+            server: Server {
+                host: host.to_string(),
+                aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+            },
+        };
+
+        let cli = config("", "", &[]);
+        let env = config("", "example.com", &[]);
+        let defaults = config("default", "localhost", &["local"]);
+        assert_eq!(
+            cli.merge_from(env).merge_from(defaults),
+            config("default", "example.com", &["local"])
+        );
+
+        assert_eq!(
+            Tuple(String::new(), None).merge_from(Tuple("value".to_string(), Some("other".to_string()))),
+            Tuple("value".to_string(), Some("other".to_string()))
+        );
+    }
+
+    #[test]
+    fn derive_generics() {
+        // Not `Clone` or `IsEmpty`, `Vec<T>` implements `MergeEmpty` anyway
+        #[derive(Debug, PartialEq)]
+        struct Job(u8);
+
+        #[derive(MergeEmpty, Debug, PartialEq)]
+        struct Queue<T> {
+            jobs: Vec<T>,
+            name: Option<String>,
+        }
+
+        let queue = |jobs: Vec<Job>, name: Option<&str>| Queue {
+            jobs,
+            name: name.map(String::from),
+        };
+        assert_eq!(
+            queue(Vec::new(), Some("main")).merge_from(queue(vec![Job(1)], Some("other"))),
+            queue(vec![Job(1)], Some("main"))
+        );
+    }
+}
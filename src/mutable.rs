@@ -0,0 +1,180 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::mem;
+
+use crate::{IfEmpty, IsEmpty};
+
+/// For checking IfEmpty in place
+///
+/// Implemented for all types implementing `IsEmpty`.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfEmptyMut;
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let mut config = Config {
+///     name: String::new(),
+/// };
+/// config.name.set_if_empty("anonymous".into());
+/// assert_eq!(config.name, "anonymous");
+/// ```
+pub trait IfEmptyMut: IsEmpty + Sized {
+    /// Replaces `self` with `val` if `self` is empty
+    fn set_if_empty(&mut self, val: Self) {
+        if self.is_empty() {
+            *self = val;
+        }
+    }
+
+    /// Replaces `self` with `val` if `self` is empty
+    ///
+    /// Returns `true` if `self` was replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IfEmptyMut;
+    ///
+    /// let mut names = vec![String::new(), "ferris".to_string()];
+    /// let mut defaulted = 0;
+    /// for name in &mut names {
+    ///     if name.replace_if_empty("anonymous".to_string()) {
+    ///         defaulted += 1;
+    ///     }
+    /// }
+    /// assert_eq!(defaulted, 1);
+    /// ```
+    fn replace_if_empty(&mut self, val: Self) -> bool {
+        let empty = self.is_empty();
+        if empty {
+            *self = val;
+        }
+        empty
+    }
+
+    /// Replaces `self` with `val` if `self` is empty and returns a mutable reference to it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IfEmptyMut;
+    ///
+    /// let mut path = String::new();
+    /// path.get_or_fill_if_empty("/usr".to_string()).push_str("/local");
+    /// assert_eq!(path, "/usr/local");
+    /// ```
+    fn get_or_fill_if_empty(&mut self, val: Self) -> &mut Self {
+        self.set_if_empty(val);
+        self
+    }
+
+    /// Replaces `self` with the result of `f` if `self` is empty and returns a mutable reference
+    /// to it
+    ///
+    /// `f` is only called if `self` is empty.
+    fn get_or_fill_if_empty_with<F: FnOnce() -> Self>(&mut self, f: F) -> &mut Self {
+        if self.is_empty() {
+            *self = f();
+        }
+        self
+    }
+
+    /// Applies [`IfEmpty::if_empty`] in place
+    ///
+    /// The value is taken out with [`mem::take`] and the result put back, so no clone is needed
+    /// for non-empty values.
+    ///
+    /// [`mem::take`]: https://doc.rust-lang.org/std/mem/fn.take.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::IfEmptyMut;
+    ///
+    /// let mut rows = vec![vec![1; 1024], Vec::new()];
+    /// for row in &mut rows {
+    ///     row.if_empty_take(vec![0]);
+    /// }
+    /// assert_eq!(rows[1], [0]);
+    /// ```
+    fn if_empty_take(&mut self, fallback: Self)
+    where
+        Self: IfEmpty + Default,
+    {
+        *self = mem::take(self).if_empty(fallback);
+    }
+}
+
+impl<T: IsEmpty> IfEmptyMut for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use crate::IfEmptyMut;
+
+    #[test]
+    fn set_if_empty() {
+        let mut string = String::new();
+        string.set_if_empty("fallback".to_string());
+        assert_eq!(string, "fallback");
+        string.set_if_empty("other".to_string());
+        assert_eq!(string, "fallback");
+
+        let mut os_string = OsString::new();
+        os_string.set_if_empty(OsString::from("fallback"));
+        assert_eq!(os_string, OsString::from("fallback"));
+
+        let mut vec = Vec::new();
+        vec.set_if_empty(vec![1]);
+        assert_eq!(vec, [1]);
+
+        let mut str = "";
+        str.set_if_empty("fallback");
+        assert_eq!(str, "fallback");
+    }
+
+    #[test]
+    fn replace_if_empty() {
+        let mut string = String::new();
+        assert!(string.replace_if_empty("fallback".to_string()));
+        assert_eq!(string, "fallback");
+        assert!(!string.replace_if_empty("other".to_string()));
+        assert_eq!(string, "fallback");
+    }
+
+    #[test]
+    fn if_empty_take() {
+        let mut string = String::new();
+        string.if_empty_take("fallback".to_string());
+        assert_eq!(string, "fallback");
+
+        let mut vec = vec![1];
+        let ptr = vec.as_ptr();
+        vec.if_empty_take(vec![2]);
+        assert_eq!(vec, [1]);
+        assert_eq!(vec.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn get_or_fill_if_empty() {
+        let mut vec = Vec::new();
+        vec.get_or_fill_if_empty(vec![1]).push(2);
+        assert_eq!(vec, [1, 2]);
+        vec.get_or_fill_if_empty(vec![3]).push(4);
+        assert_eq!(vec, [1, 2, 4]);
+
+        let mut string = "value".to_string();
+        string.get_or_fill_if_empty_with(|| unreachable!()).push('!');
+        assert_eq!(string, "value!");
+        let mut string = String::new();
+        assert_eq!(string.get_or_fill_if_empty_with(|| "fallback".to_string()), "fallback");
+    }
+}
@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// For replacing unspecified network addresses
+///
+/// Addresses are unspecified if they are `0.0.0.0` or `::`. Socket addresses are unspecified if their address is
+/// unspecified or their port is `0`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::SocketAddr;
+///
+/// use if_empty::IfUnspecified;
+///
+/// let default: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+/// let bind: SocketAddr = "0.0.0.0:0".parse().unwrap();
+/// assert_eq!(bind.if_unspecified(default), default);
+/// ```
+pub trait IfUnspecified {
+    /// Returns `true` if `self` is unspecified
+    fn is_unspecified(&self) -> bool;
+
+    /// Returns `val` if `self` is unspecified
+    fn if_unspecified(self, val: Self) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_unspecified() {
+            val
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! if_unspecified_ip {
+    ($($type:ty),*) => {
+        $(
+            /// Implementation of `IfUnspecified` for
+            #[doc = concat!("[`", stringify!($type), "`]")]
+            impl IfUnspecified for $type {
+                fn is_unspecified(&self) -> bool {
+                    <$type>::is_unspecified(self)
+                }
+            }
+        )*
+    };
+}
+
+if_unspecified_ip!(IpAddr, Ipv4Addr, Ipv6Addr);
+
+macro_rules! if_unspecified_socket {
+    ($($type:ty),*) => {
+        $(
+            /// Implementation of `IfUnspecified` for
+            #[doc = concat!("[`", stringify!($type), "`]")]
+            impl IfUnspecified for $type {
+                fn is_unspecified(&self) -> bool {
+                    self.ip().is_unspecified() || self.port() == 0
+                }
+            }
+        )*
+    };
+}
+
+if_unspecified_socket!(SocketAddr, SocketAddrV4, SocketAddrV6);
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    use crate::IfUnspecified;
+
+    #[test]
+    fn ip() {
+        let localhost = Ipv4Addr::LOCALHOST;
+        assert_eq!(Ipv4Addr::UNSPECIFIED.if_unspecified(localhost), localhost);
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 0, 1).if_unspecified(localhost),
+            Ipv4Addr::new(10, 0, 0, 1)
+        );
+        assert_eq!(Ipv6Addr::UNSPECIFIED.if_unspecified(Ipv6Addr::LOCALHOST), Ipv6Addr::LOCALHOST);
+        assert_eq!(
+            IpAddr::from(Ipv6Addr::UNSPECIFIED).if_unspecified(localhost.into()),
+            IpAddr::from(localhost)
+        );
+    }
+
+    #[test]
+    fn socket() {
+        let default = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080);
+        assert_eq!(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 80).if_unspecified(default), default);
+        assert_eq!(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0).if_unspecified(default), default);
+        assert_eq!(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80).if_unspecified(default).port(), 80);
+
+        let default = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0);
+        assert_eq!(
+            SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 80, 0, 0).if_unspecified(default),
+            default
+        );
+        assert_eq!(
+            SocketAddr::from(default).if_unspecified("[::]:0".parse().unwrap()).port(),
+            8080
+        );
+    }
+}
@@ -0,0 +1,381 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
+
+use crate::{EmptyError, IsEmpty};
+
+/// A value which is guaranteed to not be empty
+///
+/// The invariant is checked once on construction, so APIs taking a `NonEmpty<T>` don't have to
+/// sprinkle `if_empty` calls to defend against empty input.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::NonEmpty;
+///
+/// assert!(NonEmpty::new(String::new()).is_err());
+///
+/// let name = NonEmpty::new_or(String::new(), "anonymous".to_string());
+/// assert_eq!(name.into_inner(), "anonymous");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmpty<T>(T);
+
+impl<T: IsEmpty> NonEmpty<T> {
+    /// Wraps `value` or returns an [`EmptyError`] if it is empty
+    pub fn new(value: T) -> Result<Self, EmptyError> {
+        if value.is_empty() {
+            Err(EmptyError::new::<T>())
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Wraps `value` or `fallback` if `value` is empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fallback` is empty as well.
+    pub fn new_or(value: T, fallback: T) -> Self {
+        match Self::new(value) {
+            Ok(non_empty) => non_empty,
+            Err(_) => Self::new(fallback).expect("fallback for NonEmpty must not be empty"),
+        }
+    }
+}
+
+macro_rules! non_empty_try_from {
+    ($($(#[$generic:ident])? $type:ty),*) => {
+        $(
+            impl$(<$generic>)? TryFrom<$type> for NonEmpty<$type> {
+                type Error = EmptyError;
+
+                fn try_from(value: $type) -> Result<Self, EmptyError> {
+                    Self::new(value)
+                }
+            }
+        )*
+    };
+}
+
+non_empty_try_from!(String, std::ffi::OsString, #[T] Vec<T>);
+
+impl<T> NonEmpty<T> {
+    /// Returns the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns a reference to the wrapped value
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> Deref for NonEmpty<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for NonEmpty<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// A [`String`] which is guaranteed to not be empty
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::NonEmptyString;
+///
+/// let name: NonEmptyString = "ferris".parse().unwrap();
+/// assert_eq!(name.as_str(), "ferris");
+/// assert!("".parse::<NonEmptyString>().is_err());
+///
+/// let name = NonEmptyString::new_or(String::new(), "anonymous");
+/// assert_eq!(name.to_string(), "anonymous");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(String);
+
+impl NonEmptyString {
+    /// Wraps `value` or returns an [`EmptyError`] if it is empty
+    pub fn new(value: String) -> Result<Self, EmptyError> {
+        if value.is_empty() {
+            Err(EmptyError::new::<String>())
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Wraps `value` or `fallback` if `value` is empty
+    ///
+    /// The fallback is only allocated if it is actually used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fallback` is empty as well.
+    pub fn new_or(value: String, fallback: &str) -> Self {
+        match Self::new(value) {
+            Ok(non_empty) => non_empty,
+            Err(_) => Self::new(fallback.to_string()).expect("fallback for NonEmptyString must not be empty"),
+        }
+    }
+
+    /// Returns the wrapped string as a [`str`] slice
+    ///
+    /// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the wrapped [`String`]
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyError;
+
+    fn try_from(value: String) -> Result<Self, EmptyError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyError;
+
+    fn try_from(value: &str) -> Result<Self, EmptyError> {
+        Self::new(value.to_string())
+    }
+}
+
+impl FromStr for NonEmptyString {
+    type Err = EmptyError;
+
+    fn from_str(s: &str) -> Result<Self, EmptyError> {
+        Self::new(s.to_string())
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<NonEmptyString> for String {
+    fn from(value: NonEmptyString) -> Self {
+        value.0
+    }
+}
+
+/// A [`Vec`] which is guaranteed to contain at least one element
+///
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use if_empty::NonEmptyVec;
+///
+/// let hosts = NonEmptyVec::new(Vec::new(), "localhost");
+/// assert_eq!(*hosts.first(), "localhost");
+/// assert!(NonEmptyVec::<u8>::try_from(Vec::new()).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVec<T>(Vec<T>);
+
+impl<T> NonEmptyVec<T> {
+    /// Wraps `vec` or a vector only containing `fallback` if `vec` is empty
+    pub fn new(mut vec: Vec<T>, fallback: T) -> Self {
+        if vec.is_empty() {
+            vec.push(fallback);
+        }
+        Self(vec)
+    }
+
+    /// Returns the first element
+    pub fn first(&self) -> &T {
+        &self.0[0]
+    }
+
+    /// Returns the last element
+    pub fn last(&self) -> &T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Appends an element to the back of the vector
+    pub fn push(&mut self, value: T) {
+        self.0.push(value);
+    }
+
+    /// Returns the elements as a slice
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the wrapped [`Vec`]
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> TryFrom<Vec<T>> for NonEmptyVec<T> {
+    type Error = EmptyError;
+
+    fn try_from(vec: Vec<T>) -> Result<Self, EmptyError> {
+        if vec.is_empty() {
+            Err(EmptyError::new::<Vec<T>>())
+        } else {
+            Ok(Self(vec))
+        }
+    }
+}
+
+impl<T> Deref for NonEmptyVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptyVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for Vec<T> {
+    fn from(value: NonEmptyVec<T>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::TryFrom, ffi::OsString};
+
+    use crate::{EmptyError, NonEmpty, NonEmptyString, NonEmptyVec};
+
+    #[test]
+    fn new() {
+        assert_eq!(NonEmpty::new(String::new()), Err(EmptyError::new::<String>()));
+        assert_eq!(NonEmpty::new("text".to_string()).unwrap().into_inner(), "text");
+        assert_eq!(NonEmpty::new(OsString::from("text")).unwrap().get(), &OsString::from("text"));
+    }
+
+    #[test]
+    fn new_or() {
+        assert_eq!(
+            NonEmpty::new_or(String::new(), "fallback".to_string()).into_inner(),
+            "fallback"
+        );
+        assert_eq!(
+            NonEmpty::new_or("value".to_string(), "fallback".to_string()).into_inner(),
+            "value"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_or_empty_fallback() {
+        NonEmpty::new_or(String::new(), String::new());
+    }
+
+    #[test]
+    fn deref() {
+        let value = NonEmpty::new("text".to_string()).unwrap();
+        assert_eq!(value.len(), 4);
+    }
+
+    #[test]
+    fn non_empty_string() {
+        assert_eq!(NonEmptyString::new(String::new()), Err(EmptyError::new::<String>()));
+        assert_eq!(NonEmptyString::new("text".to_string()).unwrap().as_str(), "text");
+        assert_eq!(NonEmptyString::new_or(String::new(), "fallback").into_string(), "fallback");
+        assert_eq!(NonEmptyString::new_or("value".to_string(), "fallback").into_string(), "value");
+    }
+
+    #[test]
+    fn non_empty_string_traits() {
+        let value: NonEmptyString = "text".parse().unwrap();
+        assert_eq!(value.to_string(), "text");
+        assert_eq!(AsRef::<str>::as_ref(&value), "text");
+        assert_eq!(value.len(), 4);
+        assert_eq!(String::from(value), "text");
+        assert_eq!("".parse::<NonEmptyString>(), Err(EmptyError::new::<String>()));
+    }
+
+    #[test]
+    fn non_empty_vec() {
+        let vec = NonEmptyVec::new(Vec::new(), 1);
+        assert_eq!(vec.first(), &1);
+        assert_eq!(vec.last(), &1);
+
+        let mut vec = NonEmptyVec::new(vec![1, 2], 3);
+        vec.push(4);
+        assert_eq!(vec.first(), &1);
+        assert_eq!(vec.last(), &4);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.as_slice(), &[1, 2, 4]);
+        assert_eq!(Vec::from(vec), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn non_empty_vec_try_from() {
+        assert_eq!(NonEmptyVec::<u8>::try_from(Vec::new()), Err(EmptyError::new::<Vec<u8>>()));
+        assert_eq!(NonEmptyVec::try_from(vec![1]).unwrap().into_vec(), vec![1]);
+    }
+
+    #[test]
+    fn try_from() {
+        fn validate(name: String, path: OsString) -> Result<(NonEmptyString, NonEmpty<OsString>), EmptyError> {
+            Ok((NonEmptyString::try_from(name)?, NonEmpty::try_from(path)?))
+        }
+
+        assert!(validate("name".to_string(), OsString::from("path")).is_ok());
+        assert_eq!(
+            validate(String::new(), OsString::from("path")).unwrap_err(),
+            EmptyError::new::<String>()
+        );
+        assert_eq!(
+            validate("name".to_string(), OsString::new()).unwrap_err(),
+            EmptyError::new::<OsString>()
+        );
+        assert_eq!(NonEmptyString::try_from("text").unwrap().as_str(), "text");
+        assert_eq!(NonEmpty::try_from(vec![1]).unwrap().into_inner(), vec![1]);
+    }
+}
@@ -0,0 +1,128 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::time::Duration;
+
+use crate::{IfEmpty, IsEmpty};
+
+/// For replacing NaN floating point values
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::IfNan;
+///
+/// assert_eq!(f64::NAN.if_nan(0.0), 0.0);
+/// assert_eq!(f64::INFINITY.if_nan_or_infinite(0.0), 0.0);
+/// assert_eq!(1.5.if_nan_or_infinite(0.0), 1.5);
+/// ```
+pub trait IfNan {
+    /// Returns `val` if `self` is NaN
+    fn if_nan(self, val: Self) -> Self;
+
+    /// Returns `val` if `self` is NaN or infinite
+    fn if_nan_or_infinite(self, val: Self) -> Self;
+}
+
+macro_rules! if_nan {
+    ($($type:ty),*) => {
+        $(
+            /// Implementation of `IfNan` for
+            #[doc = concat!("[`", stringify!($type), "`]")]
+            impl IfNan for $type {
+                fn if_nan(self, val: Self) -> Self {
+                    if self.is_nan() {
+                        val
+                    } else {
+                        self
+                    }
+                }
+
+                fn if_nan_or_infinite(self, val: Self) -> Self {
+                    if self.is_finite() {
+                        self
+                    } else {
+                        val
+                    }
+                }
+            }
+        )*
+    };
+}
+
+if_nan!(f32, f64);
+
+/// Implementation of `IsEmpty` for [`Duration`]
+///
+/// A zero duration is empty, as zero timeouts in configuration files almost always mean "not set".
+///
+/// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+impl IsEmpty for Duration {
+    fn is_empty(&self) -> bool {
+        self.as_nanos() == 0
+    }
+}
+
+/// Implementation of `IfEmpty` for [`Duration`]
+///
+/// [`Duration`]: https://doc.rust-lang.org/std/time/struct.Duration.html
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use if_empty::IfEmpty;
+///
+/// let timeout = Duration::from_secs(0);
+/// assert_eq!(timeout.if_empty(Duration::from_secs(30)), Duration::from_secs(30));
+/// ```
+impl IfEmpty for Duration {
+    /// Returns `input` if `self` is zero.
+    /// Otherwise `self` is returned.
+    fn if_empty(self, input: Self) -> Self {
+        if IsEmpty::is_empty(&self) {
+            input
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{IfEmpty, IfNan, IsEmpty};
+
+    #[test]
+    fn f32() {
+        assert_eq!(f32::NAN.if_nan(1.0), 1.0);
+        assert_eq!(f32::INFINITY.if_nan(1.0), f32::INFINITY);
+        assert_eq!(f32::NEG_INFINITY.if_nan_or_infinite(1.0), 1.0);
+        assert_eq!(2.0f32.if_nan_or_infinite(1.0), 2.0);
+    }
+
+    #[test]
+    fn f64() {
+        assert_eq!(f64::NAN.if_nan(1.0), 1.0);
+        assert_eq!(0.5f64.if_nan(1.0), 0.5);
+        assert_eq!(f64::INFINITY.if_nan_or_infinite(1.0), 1.0);
+        assert_eq!(f64::NAN.if_nan_or_infinite(1.0), 1.0);
+    }
+
+    #[test]
+    fn duration() {
+        assert!(Duration::from_secs(0).is_empty());
+        assert!(!Duration::from_nanos(1).is_empty());
+        assert_eq!(
+            Duration::from_secs(0).if_empty(Duration::from_secs(30)),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            Duration::from_millis(5).if_empty(Duration::from_secs(30)),
+            Duration::from_millis(5)
+        );
+    }
+}
@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::IsEmpty;
+
+/// Extension methods for [`Option`]s of types implementing `IsEmpty`
+///
+/// [`Option`]: https://doc.rust-lang.org/std/option/enum.Option.html
+///
+/// `Some("")` is treated like `None`.
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::OptionExt;
+///
+/// let nickname: Option<String> = Some(String::new());
+/// assert_eq!(nickname.clone().flatten_empty(), None);
+/// assert_eq!(nickname.if_none_or_empty("anonymous"), "anonymous");
+/// ```
+pub trait OptionExt<T> {
+    /// Returns the contained value or `val` if `self` is `None` or contains an empty value
+    fn if_none_or_empty<U: Into<T>>(self, val: U) -> T;
+
+    /// Returns `None` if `self` is `None` or contains an empty value
+    fn flatten_empty(self) -> Option<T>;
+}
+
+impl<T: IsEmpty> OptionExt<T> for Option<T> {
+    fn if_none_or_empty<U: Into<T>>(self, val: U) -> T {
+        match self.flatten_empty() {
+            Some(value) => value,
+            None => val.into(),
+        }
+    }
+
+    fn flatten_empty(self) -> Option<T> {
+        self.filter(|value| !value.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::OptionExt;
+
+    #[test]
+    fn if_none_or_empty() {
+        assert_eq!(None::<String>.if_none_or_empty("fallback"), "fallback");
+        assert_eq!(Some(String::new()).if_none_or_empty("fallback"), "fallback");
+        assert_eq!(Some("value".to_string()).if_none_or_empty("fallback"), "value");
+        assert_eq!(Some("").if_none_or_empty("fallback"), "fallback");
+        assert_eq!(Some(vec![1]).if_none_or_empty(vec![2]), vec![1]);
+    }
+
+    #[test]
+    fn flatten_empty() {
+        assert_eq!(None::<&str>.flatten_empty(), None);
+        assert_eq!(Some("").flatten_empty(), None);
+        assert_eq!(Some("value").flatten_empty(), Some("value"));
+    }
+}
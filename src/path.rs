@@ -0,0 +1,91 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::path::{Component, Path, PathBuf};
+
+/// For checking IfEmptyPath on paths that point nowhere useful
+///
+/// A path is empty if it has no components other than `.`, which covers `""`, `"."` and e.g. `"./."`.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use if_empty::IfEmptyPath;
+///
+/// let config = Path::new("/etc/app.toml");
+/// assert_eq!(Path::new(".").if_empty_path(config), config);
+/// assert_eq!(Path::new("").if_empty_path(config), config);
+/// assert_eq!(Path::new("app.toml").if_empty_path(config), Path::new("app.toml"));
+/// ```
+pub trait IfEmptyPath: Sized {
+    /// Returns `true` if `self` has no components other than `.`
+    fn is_empty_path(&self) -> bool;
+
+    /// Returns `val` if `self` has no components other than `.`
+    fn if_empty_path(self, val: Self) -> Self {
+        if self.is_empty_path() {
+            val
+        } else {
+            self
+        }
+    }
+}
+
+fn is_empty_path(path: &Path) -> bool {
+    path.components().all(|component| component == Component::CurDir)
+}
+
+/// Implementation of `IfEmptyPath` for [`Path`]
+///
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+impl IfEmptyPath for &Path {
+    fn is_empty_path(&self) -> bool {
+        is_empty_path(self)
+    }
+}
+
+/// Implementation of `IfEmptyPath` for [`PathBuf`]
+///
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+impl IfEmptyPath for PathBuf {
+    fn is_empty_path(&self) -> bool {
+        is_empty_path(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::IfEmptyPath;
+
+    #[test]
+    fn path() {
+        let fallback = Path::new("fallback");
+        assert_eq!(Path::new("").if_empty_path(fallback), fallback);
+        assert_eq!(Path::new(".").if_empty_path(fallback), fallback);
+        assert_eq!(Path::new("./.").if_empty_path(fallback), fallback);
+        assert_eq!(Path::new("./dir").if_empty_path(fallback), Path::new("./dir"));
+        assert_eq!(Path::new("..").if_empty_path(fallback), Path::new(".."));
+        assert_eq!(Path::new("/").if_empty_path(fallback), Path::new("/"));
+    }
+
+    #[test]
+    fn path_buf() {
+        assert_eq!(
+            PathBuf::new().if_empty_path(PathBuf::from("fallback")),
+            PathBuf::from("fallback")
+        );
+        assert_eq!(
+            PathBuf::from(".").if_empty_path(PathBuf::from("fallback")),
+            PathBuf::from("fallback")
+        );
+        assert_eq!(
+            PathBuf::from("file").if_empty_path(PathBuf::from("fallback")),
+            PathBuf::from("file")
+        );
+    }
+}
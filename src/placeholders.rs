@@ -0,0 +1,200 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Common placeholder values shown in place of empty values
+//!
+//! Using the same constants everywhere keeps output consistent, instead of every report and
+//! table inventing its own way of saying "nothing here".
+//!
+//! # Examples
+//!
+//! ```
+//! use if_empty::{
+//!     placeholders::{IfEmptyPlaceholder, EM_DASH},
+//!     IfEmptyBorrowed,
+//! };
+//!
+//! assert_eq!("".if_empty_placeholder(), "N/A");
+//! assert_eq!("".if_empty(EM_DASH), "—");
+//! ```
+
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+use crate::IsEmpty;
+
+/// "N/A", the placeholder for missing text
+pub const NOT_AVAILABLE: &str = "N/A";
+
+/// "-", a placeholder for compact output like tables
+pub const DASH: &str = "-";
+
+/// "—", a typographic placeholder for tables in rendered documents
+pub const EM_DASH: &str = "\u{2014}";
+
+/// "unknown", a placeholder for values that could not be determined, like paths
+pub const UNKNOWN: &str = "unknown";
+
+/// For types with a canonical value shown in place of an empty one
+///
+/// Unlike [`Default`] the placeholder is meant for output, e.g. "unnamed" for a name whose
+/// default is an empty string.
+///
+/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::{placeholders::Placeholder, IsEmpty};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Title(String);
+///
+/// impl IsEmpty for Title {
+///     fn is_empty(&self) -> bool {
+///         self.0.is_empty()
+///     }
+/// }
+///
+/// impl Placeholder for Title {
+///     fn placeholder() -> Self {
+///         Title("Untitled".to_string())
+///     }
+/// }
+///
+/// let title = Title(String::new()).if_empty_or_placeholder();
+/// assert_eq!(title, Title("Untitled".to_string()));
+/// ```
+pub trait Placeholder {
+    /// Returns the placeholder value
+    fn placeholder() -> Self;
+
+    /// Returns [`Placeholder::placeholder`] if `self` is empty
+    fn if_empty_or_placeholder(self) -> Self
+    where
+        Self: IsEmpty + Sized,
+    {
+        if self.is_empty() {
+            Self::placeholder()
+        } else {
+            self
+        }
+    }
+}
+
+/// Implementation of `Placeholder` for [`str`], returning [`NOT_AVAILABLE`]
+///
+/// [`str`]: https://doc.rust-lang.org/std/primitive.str.html
+impl Placeholder for &str {
+    fn placeholder() -> Self {
+        NOT_AVAILABLE
+    }
+}
+
+/// Implementation of `Placeholder` for [`String`], returning [`NOT_AVAILABLE`]
+///
+/// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+impl Placeholder for String {
+    fn placeholder() -> Self {
+        NOT_AVAILABLE.to_string()
+    }
+}
+
+/// Implementation of `Placeholder` for [`Cow`], returning [`NOT_AVAILABLE`]
+///
+/// [`Cow`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html
+impl Placeholder for Cow<'_, str> {
+    fn placeholder() -> Self {
+        Cow::Borrowed(NOT_AVAILABLE)
+    }
+}
+
+/// Implementation of `Placeholder` for [`Path`], returning [`UNKNOWN`]
+///
+/// [`Path`]: https://doc.rust-lang.org/std/path/struct.Path.html
+impl Placeholder for &Path {
+    fn placeholder() -> Self {
+        Path::new(UNKNOWN)
+    }
+}
+
+/// Implementation of `Placeholder` for [`PathBuf`], returning [`UNKNOWN`]
+///
+/// [`PathBuf`]: https://doc.rust-lang.org/std/path/struct.PathBuf.html
+impl Placeholder for PathBuf {
+    fn placeholder() -> Self {
+        PathBuf::from(UNKNOWN)
+    }
+}
+
+/// For replacing empty values with the placeholder commonly used for their type
+///
+/// Implemented for all types implementing [`Placeholder`] and `IsEmpty`, so text is replaced with
+/// [`NOT_AVAILABLE`] and paths with [`UNKNOWN`].
+pub trait IfEmptyPlaceholder {
+    /// Returns the placeholder of the type if `self` is empty
+    fn if_empty_placeholder(self) -> Self;
+}
+
+impl<T: Placeholder + IsEmpty> IfEmptyPlaceholder for T {
+    fn if_empty_placeholder(self) -> Self {
+        self.if_empty_or_placeholder()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        borrow::Cow,
+        path::{Path, PathBuf},
+    };
+
+    use super::{IfEmptyPlaceholder, Placeholder, NOT_AVAILABLE, UNKNOWN};
+
+    #[test]
+    fn text() {
+        assert_eq!("".if_empty_placeholder(), NOT_AVAILABLE);
+        assert_eq!("value".if_empty_placeholder(), "value");
+        assert_eq!(String::new().if_empty_placeholder(), NOT_AVAILABLE);
+        assert_eq!(Cow::from(String::new()).if_empty_placeholder(), NOT_AVAILABLE);
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(Path::new("").if_empty_placeholder(), Path::new(UNKNOWN));
+        assert_eq!(PathBuf::new().if_empty_placeholder(), PathBuf::from(UNKNOWN));
+        assert_eq!(PathBuf::from("/tmp").if_empty_placeholder(), PathBuf::from("/tmp"));
+    }
+
+    #[test]
+    fn placeholder() {
+        assert_eq!(<&str>::placeholder(), NOT_AVAILABLE);
+        assert_eq!(String::new().if_empty_or_placeholder(), NOT_AVAILABLE);
+        assert_eq!("value".to_string().if_empty_or_placeholder(), "value");
+        assert_eq!(PathBuf::new().if_empty_or_placeholder(), PathBuf::from(UNKNOWN));
+    }
+
+    #[test]
+    fn custom() {
+        #[derive(Debug, PartialEq)]
+        struct Tag(&'static str);
+
+        impl crate::IsEmpty for Tag {
+            fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+        }
+
+        impl Placeholder for Tag {
+            fn placeholder() -> Self {
+                Tag("untagged")
+            }
+        }
+
+        assert_eq!(Tag("").if_empty_placeholder(), Tag("untagged"));
+        assert_eq!(Tag("rust").if_empty_placeholder(), Tag("rust"));
+    }
+}
@@ -0,0 +1,57 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Commonly used traits, derive macros and macros
+//!
+//! # Examples
+//!
+//! ```
+//! use if_empty::prelude::*;
+//!
+//! let name = String::new().if_empty("anonymous".to_string());
+//! let host = coalesce!("", "localhost");
+//! let cells: Vec<_> = vec!["", "b"].into_iter().fill_empty("-").collect();
+//! assert_not_empty!(name);
+//! # assert_eq!(name, "anonymous");
+//! # assert_eq!(host, "localhost");
+//! # assert_eq!(cells, ["-", "b"]);
+//! ```
+
+#[cfg(feature = "stats")]
+pub use crate::stats::IfEmptyCounted;
+#[cfg(feature = "any")]
+pub use crate::IfEmptyAny;
+#[cfg(feature = "async")]
+pub use crate::IfEmptyAsync;
+#[cfg(feature = "numeric")]
+pub use crate::IfNan;
+pub use crate::{
+    assert_not_empty,
+    coalesce,
+    debug_assert_not_empty,
+    format_or,
+    if_empty,
+    ContextDefault,
+    EmptyReport,
+    FillDefaults,
+    IfAllEmpty,
+    IfBlank,
+    IfDefault,
+    IfEmpty,
+    IfEmptyBorrowed,
+    IfEmptyMut,
+    IfEmptyOwned,
+    IfEmptyPath,
+    IfEmptyPlaceholder,
+    IfEmptyRef,
+    IfUnspecified,
+    IsEmpty,
+    IteratorExt,
+    MapExt,
+    MergeEmpty,
+    OptionExt,
+    Placeholder,
+    RetainNonEmpty,
+    SliceExt,
+};
@@ -0,0 +1,42 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Whether a fallback was substituted for an empty value
+///
+/// Returned by [`IsEmpty::if_empty_reporting`].
+///
+/// [`IsEmpty::if_empty_reporting`]: crate::IsEmpty::if_empty_reporting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Replaced {
+    /// The value was empty and the fallback was used
+    Yes,
+    /// The value was not empty and kept
+    No,
+}
+
+impl Replaced {
+    /// Returns `true` if the fallback was used
+    pub fn is_yes(self) -> bool {
+        self == Replaced::Yes
+    }
+}
+
+impl From<Replaced> for bool {
+    fn from(replaced: Replaced) -> bool {
+        replaced.is_yes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Replaced;
+
+    #[test]
+    fn replaced() {
+        assert!(Replaced::Yes.is_yes());
+        assert!(!Replaced::No.is_yes());
+        assert!(bool::from(Replaced::Yes));
+        assert!(!bool::from(Replaced::No));
+    }
+}
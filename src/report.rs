@@ -0,0 +1,156 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::EmptyFieldsError;
+
+/// For listing which fields of a value are empty
+///
+/// Validation can report exactly which entries were left blank instead of a generic error. Use
+/// `#[derive(EmptyReport)]` to report the fields of a struct. Fields annotated with
+/// `#[if_empty(nested)]` are reported with their own fields as paths like `server.host`, fields
+/// annotated with `#[if_empty(skip)]` are not reported. [`assert_non_empty`] turns the report
+/// into an error for validating e.g. configuration at startup.
+///
+/// [`assert_non_empty`]: EmptyReport::assert_non_empty
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::EmptyReport;
+///
+/// #[derive(EmptyReport)]
+/// struct Server {
+///     host: String,
+///     #[if_empty(skip)]
+///     port: u16,
+/// }
+///
+/// #[derive(EmptyReport)]
+/// struct Config {
+///     name: String,
+///     #[if_empty(nested)]
+///     server: Server,
+/// }
+///
+/// let config = Config {
+///     name: String::new(),
+///     server: Server {
+///         host: String::new(),
+///         port: 80,
+///     },
+/// };
+/// assert_eq!(config.empty_fields(), ["name", "server.host"]);
+/// ```
+pub trait EmptyReport {
+    /// Appends the paths of all empty fields to `fields`, each prefixed with `prefix`
+    fn collect_empty_fields(&self, prefix: &str, fields: &mut Vec<String>);
+
+    /// Returns the paths of all empty fields
+    ///
+    /// Paths are owned strings, as nested paths are only known once the field names of all
+    /// nested types are combined.
+    fn empty_fields(&self) -> Vec<String> {
+        let mut fields = Vec::new();
+        self.collect_empty_fields("", &mut fields);
+        fields
+    }
+
+    /// Returns an error listing the empty fields if there are any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use if_empty::EmptyReport;
+    ///
+    /// #[derive(EmptyReport)]
+    /// struct Config {
+    ///     host: String,
+    ///     user: String,
+    /// }
+    ///
+    /// let config = Config {
+    ///     host: "localhost".to_string(),
+    ///     user: String::new(),
+    /// };
+    /// let error = config.assert_non_empty().unwrap_err();
+    /// assert_eq!(error.fields(), ["user"]);
+    /// ```
+    fn assert_non_empty(&self) -> Result<(), EmptyFieldsError> {
+        let fields = self.empty_fields();
+        if fields.is_empty() {
+            Ok(())
+        } else {
+            Err(EmptyFieldsError::new(fields))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EmptyReport;
+
+    #[derive(EmptyReport)]
+    struct Credentials(String, #[if_empty(skip)] bool, Vec<u8>);
+
+    #[derive(EmptyReport)]
+    struct Account<T> {
+        #[if_empty(nested)]
+        credentials: Credentials,
+        r#type: T,
+    }
+
+    #[test]
+    fn empty_fields() {
+        let credentials = Credentials(String::new(), false, vec![1]);
+        assert_eq!(credentials.empty_fields(), ["0"]);
+        assert!(!credentials.1);
+
+        let account = Account {
+            credentials,
+            r#type: "",
+        };
+        assert_eq!(account.empty_fields(), ["credentials.0", "type"]);
+
+        let mut fields = vec!["existing".to_string()];
+        Credentials(String::new(), true, Vec::new()).collect_empty_fields("login.", &mut fields);
+        assert_eq!(fields, ["existing", "login.0", "login.2"]);
+    }
+
+    #[test]
+    fn assert_non_empty() {
+        let credentials = Credentials("user".to_string(), true, vec![1]);
+        assert_eq!(credentials.assert_non_empty(), Ok(()));
+
+        let account = Account {
+            credentials,
+            r#type: String::new(),
+        };
+        let error = account.assert_non_empty().unwrap_err();
+        assert_eq!(error.fields(), ["type"]);
+        assert_eq!(error.to_string(), "empty fields: type");
+    }
+
+    #[test]
+    fn shadowed_format() {
+        #[allow(unused_macros)]
+        macro_rules! format {
+            ($($arg:tt)*) => {
+                compile_error!("the `format!` of the caller was used")
+            };
+        }
+
+        #[derive(EmptyReport)]
+        struct Profile {
+            name: String,
+            #[if_empty(nested)]
+            credentials: Credentials,
+        }
+
+        let profile = Profile {
+            name: String::new(),
+            credentials: Credentials(String::new(), false, vec![1]),
+        };
+        assert_eq!(profile.empty_fields(), ["name", "credentials.0"]);
+    }
+}
@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::IsEmpty;
+
+/// Extension methods for slices and vectors with elements that can be empty
+///
+/// # Examples
+///
+/// ```
+/// use if_empty::SliceExt;
+///
+/// let headers = ["id", "name", "email"];
+/// let mut row = vec!["1".to_string(), String::new(), String::new()];
+///
+/// row.fill_empty_with(|column| format!("<no {}>", headers[column]));
+/// assert_eq!(row, ["1", "<no name>", "<no email>"]);
+/// ```
+pub trait SliceExt<T> {
+    /// Replaces empty elements in place with the result of `f` for their index
+    ///
+    /// `f` is only called for empty elements.
+    fn fill_empty_with<F: FnMut(usize) -> T>(&mut self, f: F)
+    where
+        T: IsEmpty;
+
+    /// Returns a copy with empty elements replaced by the result of `f` for their index
+    ///
+    /// `f` is only called for empty elements.
+    fn filled_empty<F: FnMut(usize) -> T>(&self, f: F) -> Vec<T>
+    where
+        T: IsEmpty + Clone;
+}
+
+/// Implementation of `SliceExt` for slices, which vectors dereference to
+impl<T> SliceExt<T> for [T] {
+    fn fill_empty_with<F: FnMut(usize) -> T>(&mut self, mut f: F)
+    where
+        T: IsEmpty,
+    {
+        for (index, element) in self.iter_mut().enumerate() {
+            if element.is_empty() {
+                *element = f(index);
+            }
+        }
+    }
+
+    fn filled_empty<F: FnMut(usize) -> T>(&self, mut f: F) -> Vec<T>
+    where
+        T: IsEmpty + Clone,
+    {
+        self.iter()
+            .enumerate()
+            .map(|(index, element)| if element.is_empty() { f(index) } else { element.clone() })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SliceExt;
+
+    #[test]
+    fn fill_empty_with() {
+        let mut cells = ["a", "", "c", ""];
+        cells.fill_empty_with(|index| if index == 1 { "b" } else { "-" });
+        assert_eq!(cells, ["a", "b", "c", "-"]);
+
+        let mut rows = vec![vec![1], vec![]];
+        rows.fill_empty_with(|index| vec![index]);
+        assert_eq!(rows, [vec![1], vec![1]]);
+    }
+
+    #[test]
+    fn filled_empty() {
+        let cells = vec![String::new(), "b".to_string()];
+        assert_eq!(cells.filled_empty(|_| "-".to_string()), ["-", "b"]);
+        assert_eq!(cells, ["", "b"]);
+    }
+
+    #[test]
+    fn prelude() {
+        use crate::prelude::*;
+
+        // Slices and vectors are no iterators, so `IteratorExt::fill_empty_with` doesn't clash
+        let mut cells = vec!["", "b"];
+        cells.fill_empty_with(|index| if index == 0 { "a" } else { "-" });
+        assert_eq!(cells, ["a", "b"]);
+        let cells: Vec<_> = cells.into_iter().fill_empty_with(|| "-").collect();
+        assert_eq!(cells, ["a", "b"]);
+    }
+}
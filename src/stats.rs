@@ -0,0 +1,150 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Counting how often fallbacks are used
+//!
+//! Fallbacks are counted per key, either one chosen by the caller or the call site. The counts
+//! are global for the process and can be dumped at shutdown or exposed on a debug endpoint to
+//! find configuration values that are missing more often than expected.
+//!
+//! # Examples
+//!
+//! ```
+//! use if_empty::stats::{FallbackStats, IfEmptyCounted};
+//!
+//! let host = String::new().if_empty_counted("localhost".to_string(), "doc.host");
+//! let user = "admin".if_empty_counted("guest", "doc.user");
+//! assert_eq!(FallbackStats::report().get("doc.host"), 1);
+//! assert_eq!(FallbackStats::report().get("doc.user"), 0);
+//! # assert_eq!((host.as_str(), user), ("localhost", "admin"));
+//! ```
+
+use std::{collections::BTreeMap, fmt, panic::Location, sync::Mutex};
+
+use crate::IsEmpty;
+
+static COUNTS: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+/// Process wide fallback counters
+#[derive(Debug)]
+pub struct FallbackStats;
+
+impl FallbackStats {
+    /// Counts a fallback for `key`
+    pub fn record<K: Into<String>>(key: K) {
+        *COUNTS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(key.into())
+            .or_insert(0) += 1;
+    }
+
+    /// Returns a snapshot of all counters
+    pub fn report() -> FallbackReport {
+        FallbackReport {
+            counts: COUNTS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone(),
+        }
+    }
+
+    /// Resets all counters
+    pub fn reset() {
+        COUNTS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clear();
+    }
+}
+
+/// Snapshot of fallback counters returned by [`FallbackStats::report`]
+///
+/// Displayed as one `key: count` line per key, sorted by key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FallbackReport {
+    counts: BTreeMap<String, u64>,
+}
+
+impl FallbackReport {
+    /// Returns how often a fallback was used for `key`
+    pub fn get(&self, key: &str) -> u64 {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Returns how often fallbacks were used in total
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Returns an iterator over all keys and their counts, sorted by key
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.counts.iter().map(|(key, count)| (key.as_str(), *count))
+    }
+}
+
+impl fmt::Display for FallbackReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, count) in self.iter() {
+            writeln!(f, "{}: {}", key, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// For checking IfEmpty while counting used fallbacks in [`FallbackStats`]
+///
+/// Implemented for all types implementing `IsEmpty`.
+pub trait IfEmptyCounted: IsEmpty + Sized {
+    /// Returns `val` if `self` is empty and counts it for `key`
+    fn if_empty_counted<K: Into<String>>(self, val: Self, key: K) -> Self {
+        if self.is_empty() {
+            FallbackStats::record(key);
+            val
+        } else {
+            self
+        }
+    }
+
+    /// Returns `val` if `self` is empty and counts it for the call site
+    ///
+    /// The key is the location of the call as `file:line:column`.
+    #[track_caller]
+    fn if_empty_tracked(self, val: Self) -> Self {
+        if self.is_empty() {
+            FallbackStats::record(Location::caller().to_string());
+            val
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: IsEmpty> IfEmptyCounted for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::{FallbackStats, IfEmptyCounted};
+
+    #[test]
+    fn counted() {
+        assert_eq!(Vec::<u8>::new().if_empty_counted(vec![1], "test.counted"), [1]);
+        assert_eq!("".if_empty_counted("fallback", "test.counted"), "fallback");
+        assert_eq!("value".if_empty_counted("fallback", "test.counted"), "value");
+
+        let report = FallbackStats::report();
+        assert_eq!(report.get("test.counted"), 2);
+        assert!(report.total() >= 2);
+        assert!(report.to_string().contains("test.counted: 2\n"));
+    }
+
+    #[test]
+    fn tracked() {
+        let line = line!() + 1;
+        let value = String::new().if_empty_tracked("fallback".to_string());
+        assert_eq!(value, "fallback");
+
+        let site = format!("{}:{}:", file!(), line);
+        let report = FallbackStats::report();
+        let (_, count) = report
+            .iter()
+            .find(|(key, _)| key.starts_with(&site))
+            .expect("call site counted");
+        assert_eq!(count, 1);
+    }
+}